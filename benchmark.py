@@ -206,7 +206,7 @@ def main():
         name="process_pydantic_users (getattr + PyResult)"
     )
     results.append(result_pydantic)
-    total_age, active_count, elapsed = result_pydantic["result"]
+    total_age, active_count, elapsed, _errors = result_pydantic["result"]
     print(f"    Result: total_age={total_age:,}, active_count={active_count:,}, elapsed={elapsed:.2f} μs")
     
     # PyO3 version - direct field access
@@ -0,0 +1,65 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::BTreeMap;
+
+use crate::user::User;
+
+/// Computes, for each email domain, the fraction of users at that domain who are active.
+///
+/// Domains are lowercased as in `domain_frequencies`, and emails without an `@` are skipped.
+/// Only domains with at least one user appear, so every ratio is well defined.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A dictionary mapping each lowercased domain to a ratio in `0.0..=1.0`, with keys in sorted order
+///
+/// # Examples
+///
+/// ```ignore
+/// active_ratio_by_domain(users)  # {"example.com": 0.5, "test.org": 1.0}
+/// ```
+#[pyfunction]
+pub fn active_ratio_by_domain<'py>(py: Python<'py>, users: Vec<PyRef<'py, User>>) -> PyResult<Bound<'py, PyDict>> {
+    // domain -> (active users, all users)
+    let mut counts: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for user in &users {
+        if let Some(domain) = user.email_domain() {
+            let (active, total) = counts.entry(domain).or_default();
+            *active += usize::from(user.is_active());
+            *total += 1;
+        }
+    }
+    let dict = PyDict::new(py);
+    for (domain, (active, total)) in counts {
+        dict.set_item(domain, active as f64 / total as f64)?;
+    }
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, bind, borrow_all, user, with_py};
+
+    #[test]
+    fn ratios_per_domain() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![
+                    user(1, "A", "a@Example.com", 30, true),
+                    user(2, "B", "b@example.com", 30, false),
+                    user(3, "C", "c@test.org", 30, true),
+                    user(4, "D", "no-at-sign", 30, false),
+                ],
+            );
+            let ratios = active_ratio_by_domain(py, borrow_all(&users)).unwrap();
+            assert_py_eq(&ratios, c"{'example.com': 0.5, 'test.org': 1.0}");
+        });
+    }
+}
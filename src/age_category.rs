@@ -0,0 +1,98 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Returns the label for `age`, or `None` if it is negative.
+fn category_label(age: i32) -> Option<&'static str> {
+    match age {
+        i32::MIN..=-1 => None,
+        0..=17 => Some("minor"),
+        18..=64 => Some("adult"),
+        65.. => Some("senior"),
+    }
+}
+
+/// Maps an age to `"minor"` (under 18), `"adult"` (18 to 64), or `"senior"` (65 and over).
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `age` is negative.
+///
+/// # Examples
+///
+/// ```ignore
+/// age_category(17)  # "minor"
+/// age_category(65)  # "senior"
+/// ```
+#[pyfunction]
+pub fn age_category(age: i32) -> PyResult<String> {
+    category_label(age)
+        .map(str::to_string)
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("age must not be negative, got {}", age)))
+}
+
+/// Returns the `age_category` label of each user, in input order.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Errors
+///
+/// Returns a `PyValueError` naming the user id if any user has a negative age.
+///
+/// # Examples
+///
+/// ```ignore
+/// categorize_users(users)  # ["adult", "minor", "senior"]
+/// ```
+#[pyfunction]
+pub fn categorize_users(users: Vec<PyRef<User>>) -> PyResult<Vec<String>> {
+    users
+        .iter()
+        .map(|user| {
+            category_label(user.age).map(str::to_string).ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!(
+                    "user {}: age must not be negative, got {}",
+                    user.id, user.age
+                ))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, message, user, with_py};
+
+    #[test]
+    fn category_label_boundaries() {
+        let labels: Vec<_> = [0, 17, 18, 64, 65, i32::MAX].into_iter().map(category_label).collect();
+        assert_eq!(
+            labels,
+            [Some("minor"), Some("minor"), Some("adult"), Some("adult"), Some("senior"), Some("senior")]
+        );
+        assert_eq!(category_label(-1), None);
+    }
+
+    #[test]
+    fn age_category_rejects_negative_ages() {
+        assert_eq!(age_category(65).unwrap(), "senior");
+        assert_eq!(message(age_category(-3).unwrap_err()), "age must not be negative, got -3");
+    }
+
+    #[test]
+    fn categorize_users_labels_each_user() {
+        with_py(|py| {
+            let users = bind(py, vec![user(1, "A", "a@x.com", 17, true), user(2, "B", "b@x.com", 65, true)]);
+            assert_eq!(categorize_users(borrow_all(&users)).unwrap(), ["minor", "senior"]);
+
+            let users = bind(py, vec![user(7, "C", "c@x.com", -1, true)]);
+            assert_eq!(
+                message(categorize_users(borrow_all(&users)).unwrap_err()),
+                "user 7: age must not be negative, got -1"
+            );
+        });
+    }
+}
@@ -0,0 +1,66 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::user::User;
+
+/// Summarizes the ages of a list of users in a single pass.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A dictionary with `count` and the `min`, `max`, and `mean` age; the last three are
+/// `None` for an empty list
+///
+/// # Examples
+///
+/// ```ignore
+/// age_summary(users)  # {"count": 3, "min": 25, "max": 41, "mean": 32.0}
+/// ```
+#[pyfunction]
+pub fn age_summary<'py>(py: Python<'py>, users: Vec<PyRef<'py, User>>) -> PyResult<Bound<'py, PyDict>> {
+    let mut count: i64 = 0;
+    let mut total: i64 = 0;
+    let mut range: Option<(i32, i32)> = None;
+    for user in &users {
+        count += 1;
+        total += user.age as i64;
+        range = Some(match range {
+            Some((min, max)) => (min.min(user.age), max.max(user.age)),
+            None => (user.age, user.age),
+        });
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("count", count)?;
+    dict.set_item("min", range.map(|(min, _)| min))?;
+    dict.set_item("max", range.map(|(_, max)| max))?;
+    dict.set_item("mean", (count > 0).then(|| total as f64 / count as f64))?;
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, bind, borrow_all, user, with_py};
+
+    #[test]
+    fn summarizes_a_known_set() {
+        with_py(|py| {
+            let users = bind(py, [30, 18, 45, 27].into_iter().map(|age| user(1, "U", "u@x.com", age, true)).collect());
+            let summary = age_summary(py, borrow_all(&users)).unwrap();
+            assert_py_eq(&summary, c"{'count': 4, 'min': 18, 'max': 45, 'mean': 30.0}");
+        });
+    }
+
+    #[test]
+    fn empty_input_has_null_statistics() {
+        with_py(|py| {
+            let summary = age_summary(py, Vec::new()).unwrap();
+            assert_py_eq(&summary, c"{'count': 0, 'min': None, 'max': None, 'mean': None}");
+        });
+    }
+}
@@ -0,0 +1,113 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::user::User;
+
+/// Running totals behind the user age aggregates.
+///
+/// Partial aggregates can be merged, which lets the same type back both the serial and
+/// the parallel implementation.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct AgeAggregate {
+    count: i64,
+    active_count: i64,
+    total_age: i64,
+    min_age: Option<i32>,
+    max_age: Option<i32>,
+}
+
+impl AgeAggregate {
+    /// Folds one user's age and active flag into the totals.
+    pub(crate) fn add(mut self, age: i32, active: bool) -> Self {
+        self.count += 1;
+        self.active_count += active as i64;
+        self.total_age += age as i64;
+        self.min_age = Some(self.min_age.map_or(age, |m| m.min(age)));
+        self.max_age = Some(self.max_age.map_or(age, |m| m.max(age)));
+        self
+    }
+
+    /// Combines two partial aggregates.
+    pub(crate) fn merge(self, other: Self) -> Self {
+        AgeAggregate {
+            count: self.count + other.count,
+            active_count: self.active_count + other.active_count,
+            total_age: self.total_age + other.total_age,
+            min_age: self.min_age.into_iter().chain(other.min_age).min(),
+            max_age: self.max_age.into_iter().chain(other.max_age).max(),
+        }
+    }
+
+    /// Builds the result dictionary; averages and extremes are `None` when there are no users.
+    pub(crate) fn into_dict(self, py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("count", self.count)?;
+        dict.set_item("active_count", self.active_count)?;
+        let avg_age = (self.count > 0).then(|| self.total_age as f64 / self.count as f64);
+        dict.set_item("avg_age", avg_age)?;
+        dict.set_item("min_age", self.min_age)?;
+        dict.set_item("max_age", self.max_age)?;
+        Ok(dict)
+    }
+}
+
+/// Computes summary statistics over a list of users in a single pass.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A dictionary with `count`, `active_count`, `avg_age`, `min_age`, and `max_age`;
+/// the last three are `None` for an empty list
+///
+/// # Examples
+///
+/// ```ignore
+/// aggregate_users(users)["avg_age"]
+/// ```
+#[pyfunction]
+pub fn aggregate_users<'py>(py: Python<'py>, users: Vec<PyRef<'py, User>>) -> PyResult<Bound<'py, PyDict>> {
+    users
+        .iter()
+        .fold(AgeAggregate::default(), |acc, user| acc.add(user.age, user.is_active()))
+        .into_dict(py)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, bind, borrow_all, user, with_py};
+
+    #[test]
+    fn aggregates_ages_and_active_count() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![
+                    user(1, "Alice", "alice@example.com", 30, true),
+                    user(2, "Bob", "bob@example.com", 20, false),
+                    user(3, "Carol", "carol@example.com", 40, true),
+                ],
+            );
+            let stats = aggregate_users(py, borrow_all(&users)).unwrap();
+            assert_py_eq(
+                &stats,
+                c"{'count': 3, 'active_count': 2, 'avg_age': 30.0, 'min_age': 20, 'max_age': 40}",
+            );
+        });
+    }
+
+    #[test]
+    fn empty_list_has_no_averages() {
+        with_py(|py| {
+            let stats = aggregate_users(py, Vec::new()).unwrap();
+            assert_py_eq(
+                &stats,
+                c"{'count': 0, 'active_count': 0, 'avg_age': None, 'min_age': None, 'max_age': None}",
+            );
+        });
+    }
+}
@@ -0,0 +1,64 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rayon::prelude::*;
+
+use crate::aggregate_users::AgeAggregate;
+use crate::user::User;
+
+/// Computes the same statistics as `aggregate_users`, releasing the GIL for the computation.
+///
+/// The ages and active flags are extracted from the Python objects while holding the GIL;
+/// the aggregation itself then runs on the rayon thread pool with the GIL released, so
+/// other Python threads can make progress meanwhile.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - Any iterable of PyO3 User objects
+///
+/// # Returns
+///
+/// A dictionary with the same shape as `aggregate_users`
+///
+/// # Examples
+///
+/// ```ignore
+/// aggregate_users_parallel(users) == aggregate_users(users)  # True
+/// ```
+#[pyfunction]
+pub fn aggregate_users_parallel<'py>(py: Python<'py>, users: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    let mut rows: Vec<(i32, bool)> = Vec::new();
+    for user_obj in users.try_iter()? {
+        let user = user_obj?.extract::<PyRef<User>>()?;
+        rows.push((user.age, user.is_active()));
+    }
+
+    let aggregate = py.detach(|| {
+        rows.par_iter()
+            .fold(AgeAggregate::default, |acc, &(age, active)| acc.add(age, active))
+            .reduce(AgeAggregate::default, AgeAggregate::merge)
+    });
+    aggregate.into_dict(py)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aggregate_users::aggregate_users;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+    use pyo3::types::PyList;
+
+    #[test]
+    fn matches_the_serial_aggregate() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                (0..1000).map(|i| user(i, "User", "user@example.com", i % 97, i % 3 == 0)).collect(),
+            );
+            let list = PyList::new(py, &users).unwrap().into_any();
+            let parallel = aggregate_users_parallel(py, list).unwrap();
+            let serial = aggregate_users(py, borrow_all(&users)).unwrap();
+            assert!(parallel.eq(&serial).unwrap(), "{} != {}", parallel, serial);
+        });
+    }
+}
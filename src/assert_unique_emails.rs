@@ -0,0 +1,70 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::user::User;
+
+/// Checks that no two users share an email address, ignoring case.
+///
+/// Users are scanned in order, so the reported conflict is the first email seen for a
+/// second time.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Errors
+///
+/// Returns a `PyValueError` naming the duplicated email and the ids of both users that have it.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_unique_emails([User(1, "Alice", "alice@example.com", 30, True), User(2, "Al", "Alice@Example.com", 30, True)])
+/// # ValueError: duplicate email 'Alice@Example.com' for user ids 1 and 2
+/// ```
+#[pyfunction]
+pub fn assert_unique_emails(users: Vec<PyRef<User>>) -> PyResult<()> {
+    let mut seen: HashMap<String, i32> = HashMap::with_capacity(users.len());
+    for user in &users {
+        if let Some(first_id) = seen.insert(user.email.to_lowercase(), user.id) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "duplicate email '{}' for user ids {} and {}",
+                user.email, first_id, user.id
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, message, user, with_py};
+
+    #[test]
+    fn distinct_emails_pass() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![user(1, "Alice", "alice@example.com", 30, true), user(2, "Bob", "bob@example.com", 25, true)],
+            );
+            assert!(assert_unique_emails(borrow_all(&users)).is_ok());
+        });
+    }
+
+    #[test]
+    fn case_differing_duplicate_names_both_ids() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![
+                    user(1, "Alice", "alice@example.com", 30, true),
+                    user(2, "Bob", "bob@example.com", 25, true),
+                    user(3, "Al", "Alice@Example.COM", 41, true),
+                ],
+            );
+            let err = assert_unique_emails(borrow_all(&users)).unwrap_err();
+            assert_eq!(message(err), "duplicate email 'Alice@Example.COM' for user ids 1 and 3");
+        });
+    }
+}
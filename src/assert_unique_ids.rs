@@ -0,0 +1,70 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::user::User;
+
+/// Checks that no two users share an `id`, as a guard before bulk inserts.
+///
+/// Users are scanned in order, so the reported id is the first one seen for a second time.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Errors
+///
+/// Returns a `PyValueError` naming the duplicated id and the positions of its first two
+/// occurrences.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_unique_ids([User(1, ...), User(2, ...)])  # passes
+/// assert_unique_ids([User(1, ...), User(2, ...), User(1, ...)])
+/// # ValueError: duplicate user id 1 at indices 0 and 2
+/// ```
+#[pyfunction]
+pub fn assert_unique_ids(users: Vec<PyRef<User>>) -> PyResult<()> {
+    let mut seen: HashMap<i32, usize> = HashMap::with_capacity(users.len());
+    for (index, user) in users.iter().enumerate() {
+        if let Some(first) = seen.insert(user.id, index) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "duplicate user id {} at indices {} and {}",
+                user.id, first, index
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, message, user, with_py};
+
+    #[test]
+    fn unique_ids_pass() {
+        with_py(|py| {
+            let users = bind(py, vec![user(1, "A", "a@x.com", 30, true), user(2, "B", "b@x.com", 30, true)]);
+            assert!(assert_unique_ids(borrow_all(&users)).is_ok());
+            assert!(assert_unique_ids(Vec::new()).is_ok());
+        });
+    }
+
+    #[test]
+    fn repeated_id_is_named() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![
+                    user(7, "A", "a@x.com", 30, true),
+                    user(2, "B", "b@x.com", 30, true),
+                    user(7, "C", "c@x.com", 30, true),
+                    user(2, "D", "d@x.com", 30, true),
+                ],
+            );
+            let err = assert_unique_ids(borrow_all(&users)).unwrap_err();
+            assert_eq!(message(err), "duplicate user id 7 at indices 0 and 2");
+        });
+    }
+}
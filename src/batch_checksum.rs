@@ -0,0 +1,62 @@
+use pyo3::prelude::*;
+
+use crate::user::{hex_encode, User};
+
+/// Computes an order-independent checksum of a list of users.
+///
+/// The SHA-256 content hashes of the users (see `User.content_hash`) are XOR-combined, so
+/// reordering the list leaves the checksum unchanged while changing any field of any user
+/// changes it. Because XOR cancels in pairs, adding or removing two identical users is not
+/// detected. An empty list yields all zeros.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A 64-character lowercase hex string
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if a user cannot be serialized.
+///
+/// # Examples
+///
+/// ```ignore
+/// batch_checksum([alice, bob]) == batch_checksum([bob, alice])  # True
+/// ```
+#[pyfunction]
+pub fn batch_checksum(users: Vec<PyRef<User>>) -> PyResult<String> {
+    let mut combined = [0u8; 32];
+    for user in &users {
+        for (acc, byte) in combined.iter_mut().zip(user.content_digest()?) {
+            *acc ^= byte;
+        }
+    }
+    Ok(hex_encode(&combined))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+
+    #[test]
+    fn is_order_independent_and_detects_changes() {
+        with_py(|py| {
+            let alice = user(1, "Alice", "alice@example.com", 30, true);
+            let bob = user(2, "Bob", "bob@example.com", 25, false);
+            let checksum = |users: Vec<User>| batch_checksum(borrow_all(&bind(py, users))).unwrap();
+
+            let forward = checksum(vec![alice.clone(), bob.clone()]);
+            assert_eq!(forward.len(), 64);
+            assert_eq!(forward, checksum(vec![bob.clone(), alice.clone()]));
+
+            let mut older = bob.clone();
+            older.age = 26;
+            assert_ne!(forward, checksum(vec![alice, older]));
+            assert_eq!(checksum(Vec::new()), "0".repeat(64));
+        });
+    }
+}
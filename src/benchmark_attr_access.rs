@@ -0,0 +1,74 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+use std::time::Instant;
+
+/// Times repeated `getattr` calls for one attribute on one object.
+///
+/// This isolates the cost of a single attribute lookup, the unit of the "border tax" paid
+/// by `process_pydantic_users` for every field it reads. The attribute name is interned
+/// once up front so only the lookup itself is measured.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `obj` - The object to read from
+/// * `attr` - The name of the attribute to look up
+/// * `iterations` - How many lookups to time
+///
+/// # Returns
+///
+/// A dictionary with `iterations`, `total_ns`, and `mean_ns` (nanoseconds per access)
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `iterations` is 0, and propagates the `AttributeError`
+/// (or any other exception) raised by the lookup.
+///
+/// # Examples
+///
+/// ```ignore
+/// benchmark_attr_access(pydantic_user, "age", 100_000)["mean_ns"]
+/// ```
+#[pyfunction]
+pub fn benchmark_attr_access<'py>(
+    py: Python<'py>,
+    obj: Bound<'py, PyAny>,
+    attr: &str,
+    iterations: usize,
+) -> PyResult<Bound<'py, PyDict>> {
+    if iterations == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("iterations must be greater than 0"));
+    }
+    let name = PyString::intern(py, attr);
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        std::hint::black_box(obj.getattr(&name)?);
+    }
+    let total_ns = start.elapsed().as_nanos() as f64;
+
+    let dict = PyDict::new(py);
+    dict.set_item("iterations", iterations)?;
+    dict.set_item("total_ns", total_ns)?;
+    dict.set_item("mean_ns", total_ns / iterations as f64)?;
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{user, with_py};
+
+    #[test]
+    fn mean_access_time_is_positive() {
+        with_py(|py| {
+            let alice = Bound::new(py, user(1, "Alice", "alice@example.com", 30, true)).unwrap().into_any();
+            let timing = benchmark_attr_access(py, alice.clone(), "age", 1000).unwrap();
+            assert!(timing.get_item("mean_ns").unwrap().unwrap().extract::<f64>().unwrap() > 0.0);
+            assert_eq!(timing.get_item("iterations").unwrap().unwrap().extract::<usize>().unwrap(), 1000);
+
+            assert!(benchmark_attr_access(py, alice.clone(), "missing", 1).is_err());
+            assert!(benchmark_attr_access(py, alice, "age", 0).is_err());
+        });
+    }
+}
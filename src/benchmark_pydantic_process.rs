@@ -8,7 +8,7 @@ use std::time::Instant;
 /// - `Ok(None)` when the object has `active == false`.
 /// - `Ok(Some(age))` when `active` is true and `age` is present and extractable.
 /// - `Err(PyErr)` if required attributes like `active` or `age` are missing or fail to extract.
-fn try_get_active_age(obj: &Bound<'_, PyAny>) -> PyResult<Option<i32>> {
+pub(crate) fn try_get_active_age(obj: &Bound<'_, PyAny>) -> PyResult<Option<i32>> {
     let active: bool = obj.getattr("active")?.extract()?;
     if !active {
         return Ok(None);
@@ -28,7 +28,7 @@ pub fn benchmark_pyo3_process<'py>(
         let user_obj = user_res?;
         let user = user_obj.extract::<PyRef<User>>()?;
         // Direct field access - no dictionary lookup, fixed memory offset
-        if user.active {
+        if user.is_active() {
             total_age += user.age as i64;
             active_count += 1;
         }
@@ -0,0 +1,77 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Instant;
+
+/// Times hashing attribute-name strings from scratch, one component of the "border tax".
+///
+/// Every `getattr` looks its name up in a dict, which needs the name's hash. CPython caches
+/// string hashes, so this hashes with a fresh SipHash state each time (the same family as
+/// CPython's string hash) to show what the lookup would pay without that cache.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `keys` - Attribute names to hash, e.g. `["id", "name", "email"]`
+/// * `iterations` - How many times to hash the whole list
+///
+/// # Returns
+///
+/// A dictionary with `hashes` (the total number hashed), `total_ns`, and `mean_ns`
+/// (nanoseconds per hash)
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `keys` is empty or `iterations` is 0.
+///
+/// # Examples
+///
+/// ```ignore
+/// benchmark_string_hash(["id", "name", "email", "age"], 100_000)["mean_ns"]
+/// ```
+#[pyfunction]
+pub fn benchmark_string_hash(py: Python<'_>, keys: Vec<String>, iterations: usize) -> PyResult<Bound<'_, PyDict>> {
+    if keys.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err("keys must not be empty"));
+    }
+    if iterations == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("iterations must be greater than 0"));
+    }
+
+    let start = Instant::now();
+    for _ in 0..iterations {
+        for key in &keys {
+            let mut hasher = DefaultHasher::new();
+            std::hint::black_box(key.as_str()).hash(&mut hasher);
+            std::hint::black_box(hasher.finish());
+        }
+    }
+    let total_ns = start.elapsed().as_nanos() as f64;
+    let hashes = keys.len() * iterations;
+
+    let dict = PyDict::new(py);
+    dict.set_item("hashes", hashes)?;
+    dict.set_item("total_ns", total_ns)?;
+    dict.set_item("mean_ns", total_ns / hashes as f64)?;
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{message, with_py};
+
+    #[test]
+    fn mean_hash_time_is_positive() {
+        with_py(|py| {
+            let keys: Vec<String> = ["id", "name", "email", "age"].map(String::from).into();
+            let timing = benchmark_string_hash(py, keys.clone(), 10_000).unwrap();
+            assert!(timing.get_item("mean_ns").unwrap().unwrap().extract::<f64>().unwrap() > 0.0);
+            assert_eq!(timing.get_item("hashes").unwrap().unwrap().extract::<usize>().unwrap(), 40_000);
+
+            assert_eq!(message(benchmark_string_hash(py, Vec::new(), 1).unwrap_err()), "keys must not be empty");
+            assert!(benchmark_string_hash(py, keys, 0).is_err());
+        });
+    }
+}
@@ -0,0 +1,98 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::greet::greet;
+
+/// A greeter that memoizes greetings on the Rust side across FFI calls
+#[pyclass]
+#[derive(Default)]
+pub struct CachingGreeter {
+    cache: HashMap<String, String>,
+}
+
+#[pymethods]
+impl CachingGreeter {
+    /// Creates a new CachingGreeter with an empty cache.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// greeter = CachingGreeter()
+    /// ```
+    #[new]
+    fn new() -> Self {
+        CachingGreeter::default()
+    }
+
+    /// Returns the greeting for `name`, formatting it only the first time the name is seen.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// greeter = CachingGreeter()
+    /// greeter.greet("Alice")  # "Hello, Alice!" (computed)
+    /// greeter.greet("Alice")  # "Hello, Alice!" (cached)
+    /// ```
+    fn greet(&mut self, name: &str) -> String {
+        if let Some(greeting) = self.cache.get(name) {
+            return greeting.clone();
+        }
+        let greeting = greet(name);
+        self.cache.insert(name.to_string(), greeting.clone());
+        greeting
+    }
+
+    /// Returns the number of distinct names currently cached.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// greeter.cache_size()
+    /// ```
+    fn cache_size(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Removes all cached greetings.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// greeter.clear_cache()
+    /// greeter.cache_size()  # 0
+    /// ```
+    fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Formats a string representation of the greeter including its cache size.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// repr(CachingGreeter())  # "CachingGreeter(cache_size=0)"
+    /// ```
+    fn __repr__(&self) -> String {
+        format!("CachingGreeter(cache_size={})", self.cache.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_greet_is_served_from_the_cache() {
+        let mut greeter = CachingGreeter::new();
+        let first = greeter.greet("Alice");
+        let second = greeter.greet("Alice");
+        assert_eq!(first, greet("Alice"));
+        assert_eq!(second, first);
+        assert_eq!(greeter.cache_size(), 1);
+
+        greeter.greet("Bob");
+        assert_eq!(greeter.__repr__(), "CachingGreeter(cache_size=2)");
+        greeter.clear_cache();
+        assert_eq!(greeter.cache_size(), 0);
+    }
+}
@@ -1,39 +1,474 @@
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::calculator_snapshot::CalculatorSnapshot;
+use crate::user::parse_json;
 
 /// A simple calculator with stateful operations
 #[pyclass(skip_from_py_object)]
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "CalculatorRecord", try_from = "CalculatorRecord")]
 pub struct Calculator {
     #[pyo3(get, set)]
     pub value: f64,
+    /// When true, operations that would produce NaN or infinity raise instead of storing it.
+    #[pyo3(get, set)]
+    pub strict: bool,
+    /// Samples accumulated with `push`, used by the statistics methods.
+    #[pyo3(get)]
+    pub samples: Vec<f64>,
+    /// Previous values, oldest first, restored one at a time by `undo`.
+    #[pyo3(get)]
+    pub history: Vec<f64>,
+    /// Maximum number of history entries kept; `None` means unbounded.
+    #[pyo3(get)]
+    pub history_limit: Option<usize>,
+    /// Named values stored by `save` and brought back by `restore`.
+    pub savepoints: BTreeMap<String, f64>,
+    /// Tolerance used by `==` and `!=`; `None` compares values exactly.
+    #[pyo3(get)]
+    pub eq_epsilon: Option<f64>,
+}
+
+impl Calculator {
+    /// Stores `result` as the new value and returns it.
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, returns a `PyValueError` and leaves the current value untouched
+    /// if `result` is NaN or infinite.
+    fn apply(&mut self, result: f64) -> PyResult<f64> {
+        self.check_strict(result)?;
+        self.record_history();
+        self.value = result;
+        Ok(self.value)
+    }
+
+    /// In strict mode, rejects a NaN or infinite `result`.
+    fn check_strict(&self, result: f64) -> PyResult<()> {
+        if self.strict && !result.is_finite() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "operation produced a non-finite value ({}) in strict mode",
+                result
+            )));
+        }
+        Ok(())
+    }
+
+    /// Pushes the current value onto the undo history, dropping the oldest entries past `history_limit`.
+    fn record_history(&mut self) {
+        self.history.push(self.value);
+        self.trim_history();
+    }
+
+    /// Drops the oldest history entries until the history fits within `history_limit`.
+    fn trim_history(&mut self) {
+        if let Some(limit) = self.history_limit {
+            if self.history.len() > limit {
+                let excess = self.history.len() - limit;
+                self.history.drain(..excess);
+            }
+        }
+    }
+
+    /// Returns the value as an `i64`, or a `PyValueError` naming `operation` if it is not an
+    /// exact integer in range.
+    fn integral_value(&self, operation: &str) -> PyResult<i64> {
+        let v = self.value;
+        if v.fract() != 0.0 || !(v >= i64::MIN as f64 && v < i64::MAX as f64) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{} requires an integral value, got {}",
+                operation, v
+            )));
+        }
+        Ok(v as i64)
+    }
+
+    /// Greatest common divisor of `|a|` and `|b|` by the Euclidean algorithm; `gcd(0, 0)` is 0.
+    fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    /// Returns `(n, d)` with `n / d` exactly equal to the finite `value` and `d` a positive power
+    /// of two, or `None` if `d` would not fit in an `i128`.
+    fn exact_ratio(value: f64) -> Option<(i128, i128)> {
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+        if mantissa == 0 {
+            return Some((0, 1));
+        }
+        let zeros = mantissa.trailing_zeros() as i32;
+        let (mantissa, exponent) = (i128::from(mantissa >> zeros), exponent + zeros);
+        let n = if value < 0.0 { -mantissa } else { mantissa };
+        if exponent >= 0 {
+            // `value` is below 2^63 in magnitude, so the shift cannot overflow.
+            Some((n << exponent, 1))
+        } else if exponent > -127 {
+            Some((n, 1 << -exponent))
+        } else {
+            None
+        }
+    }
+
+    /// Compares `a / b` with `c / d` for positive `b` and `d` without multiplying, by comparing
+    /// integer parts and then the reciprocals of the remainders.
+    fn cmp_ratio(a: u128, b: u128, c: u128, d: u128) -> std::cmp::Ordering {
+        let ordering = (a / b).cmp(&(c / d));
+        if ordering.is_ne() {
+            return ordering;
+        }
+        match (a % b, c % d) {
+            (0, 0) => std::cmp::Ordering::Equal,
+            (0, _) => std::cmp::Ordering::Less,
+            (_, 0) => std::cmp::Ordering::Greater,
+            (ra, rc) => Self::cmp_ratio(d, rc, b, ra),
+        }
+    }
+
+    /// Returns the current value, which must be positive for a logarithm to be defined.
+    fn log_domain_value(&self) -> PyResult<f64> {
+        let v = self.value;
+        if v <= 0.0 || v.is_nan() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "logarithm requires a positive value, got {}",
+                v
+            )));
+        }
+        Ok(v)
+    }
+
+    /// Validates a shift amount, which must be in `0..64`.
+    fn shift_amount(bits: i64) -> PyResult<u32> {
+        u32::try_from(bits)
+            .ok()
+            .filter(|&b| b < i64::BITS)
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("shift amount must be in 0..64, got {}", bits)))
+    }
+
+    /// Returns the pushed samples, or a `PyValueError` if none have been pushed.
+    fn require_samples(&self) -> PyResult<&[f64]> {
+        if self.samples.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err("no samples have been pushed"));
+        }
+        Ok(&self.samples)
+    }
+}
+
+/// A float in the persisted Calculator state.
+///
+/// serde_json writes NaN and infinities as `null`, which cannot be read back as a float.
+/// Finite values are written as plain numbers and the others as the strings `"NaN"`,
+/// `"Infinity"`, and `"-Infinity"`, so every value survives a round trip.
+#[derive(Clone, Copy)]
+struct JsonFloat(f64);
+
+impl Serialize for JsonFloat {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            v if v.is_finite() => serializer.serialize_f64(v),
+            v if v.is_nan() => serializer.serialize_str("NaN"),
+            v if v > 0.0 => serializer.serialize_str("Infinity"),
+            _ => serializer.serialize_str("-Infinity"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonFloat {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct JsonFloatVisitor;
+
+        impl serde::de::Visitor<'_> for JsonFloatVisitor {
+            type Value = JsonFloat;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(r#"a number, "NaN", "Infinity", or "-Infinity""#)
+            }
+
+            fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<JsonFloat, E> {
+                Ok(JsonFloat(v))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<JsonFloat, E> {
+                Ok(JsonFloat(v as f64))
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<JsonFloat, E> {
+                Ok(JsonFloat(v as f64))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<JsonFloat, E> {
+                match v {
+                    "NaN" => Ok(JsonFloat(f64::NAN)),
+                    "Infinity" => Ok(JsonFloat(f64::INFINITY)),
+                    "-Infinity" => Ok(JsonFloat(f64::NEG_INFINITY)),
+                    _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(JsonFloatVisitor)
+    }
+}
+
+/// The persisted JSON shape of a `Calculator`; every field but `value` may be omitted.
+#[derive(Serialize, Deserialize)]
+struct CalculatorRecord {
+    value: JsonFloat,
+    #[serde(default)]
+    strict: bool,
+    #[serde(default)]
+    samples: Vec<JsonFloat>,
+    #[serde(default)]
+    history: Vec<JsonFloat>,
+    #[serde(default)]
+    history_limit: Option<usize>,
+    #[serde(default)]
+    savepoints: BTreeMap<String, JsonFloat>,
+    #[serde(default)]
+    eq_epsilon: Option<JsonFloat>,
+}
+
+impl From<Calculator> for CalculatorRecord {
+    fn from(calc: Calculator) -> Self {
+        let floats = |values: Vec<f64>| values.into_iter().map(JsonFloat).collect();
+        CalculatorRecord {
+            value: JsonFloat(calc.value),
+            strict: calc.strict,
+            samples: floats(calc.samples),
+            history: floats(calc.history),
+            history_limit: calc.history_limit,
+            savepoints: calc.savepoints.into_iter().map(|(name, v)| (name, JsonFloat(v))).collect(),
+            eq_epsilon: calc.eq_epsilon.map(JsonFloat),
+        }
+    }
+}
+
+impl TryFrom<CalculatorRecord> for Calculator {
+    type Error = String;
+
+    fn try_from(record: CalculatorRecord) -> Result<Self, Self::Error> {
+        let eq_epsilon = record.eq_epsilon.map(|v| v.0);
+        if let Some(reason) = eq_epsilon_error(eq_epsilon) {
+            return Err(reason);
+        }
+        let floats = |values: Vec<JsonFloat>| values.into_iter().map(|v| v.0).collect();
+        Ok(Calculator {
+            value: record.value.0,
+            strict: record.strict,
+            samples: floats(record.samples),
+            history: floats(record.history),
+            history_limit: record.history_limit,
+            savepoints: record.savepoints.into_iter().map(|(name, v)| (name, v.0)).collect(),
+            eq_epsilon,
+        })
+    }
+}
+
+/// Describes why `eq_epsilon` is unusable as a tolerance, or returns `None` if it is `None`
+/// or a non-negative number.
+fn eq_epsilon_error(eq_epsilon: Option<f64>) -> Option<String> {
+    match eq_epsilon {
+        Some(epsilon) if epsilon.is_nan() || epsilon < 0.0 => {
+            Some(format!("eq_epsilon must be a non-negative number, got {}", epsilon))
+        }
+        _ => None,
+    }
+}
+
+/// Checks `eq_epsilon` with `eq_epsilon_error`, raising a `PyValueError` if it is unusable.
+fn check_eq_epsilon(eq_epsilon: Option<f64>) -> PyResult<Option<f64>> {
+    match eq_epsilon_error(eq_epsilon) {
+        Some(reason) => Err(pyo3::exceptions::PyValueError::new_err(reason)),
+        None => Ok(eq_epsilon),
+    }
 }
 
 #[pymethods]
 impl Calculator {
     /// Creates a new Calculator with its internal value set to `initial_value`.
     ///
+    /// Pass `strict=True` to make operations that produce NaN or infinity raise, and
+    /// `history_limit` to cap how many previous values are kept for `undo`, and `eq_epsilon`
+    /// to make equality comparisons tolerate rounding error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `eq_epsilon` is negative or NaN.
+    ///
     /// # Examples
     ///
     /// ```ignore
     /// calc = Calculator(3.5)
     /// calc.value
+    /// strict_calc = Calculator(1.0, strict=True)
+    /// bounded_calc = Calculator(0.0, history_limit=100)
+    /// tolerant_calc = Calculator(0.1, eq_epsilon=1e-9)
     /// ```
     #[new]
-    fn new(initial_value: f64) -> Self {
-        Calculator { value: initial_value }
+    #[pyo3(signature = (initial_value, strict=false, history_limit=None, eq_epsilon=None))]
+    fn new(initial_value: f64, strict: bool, history_limit: Option<usize>, eq_epsilon: Option<f64>) -> PyResult<Self> {
+        Ok(Calculator {
+            value: initial_value,
+            strict,
+            samples: Vec::new(),
+            history: Vec::new(),
+            history_limit,
+            savepoints: BTreeMap::new(),
+            eq_epsilon: check_eq_epsilon(eq_epsilon)?,
+        })
+    }
+
+    /// Sets the tolerance used by `==` and `!=`; `None` restores exact comparison.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `eq_epsilon` is negative or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc.eq_epsilon = 1e-9
+    /// ```
+    #[setter]
+    fn set_eq_epsilon(&mut self, eq_epsilon: Option<f64>) -> PyResult<()> {
+        self.eq_epsilon = check_eq_epsilon(eq_epsilon)?;
+        Ok(())
+    }
+
+    /// Sets the maximum number of history entries, immediately dropping the oldest entries
+    /// beyond the new limit. `None` removes the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc.history_limit = 10
+    /// ```
+    #[setter]
+    fn set_history_limit(&mut self, history_limit: Option<usize>) {
+        self.history_limit = history_limit;
+        self.trim_history();
+    }
+
+    /// Restores the value from before the most recent operation and returns it.
+    ///
+    /// Every operation that changes the value (including `reset`) records the previous value.
+    /// Assigning `calc.value` directly does not.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if there is nothing to undo.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(2.0)
+    /// calc.add(3.0)
+    /// calc.undo()  # 2.0
+    /// ```
+    fn undo(&mut self) -> PyResult<f64> {
+        self.value = self
+            .history
+            .pop()
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("nothing to undo"))?;
+        Ok(self.value)
+    }
+
+    /// Stores the current value under `name`, overwriting any savepoint with the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(2.0)
+    /// calc.save("before")
+    /// ```
+    fn save(&mut self, name: String) {
+        self.savepoints.insert(name, self.value);
+    }
+
+    /// Sets the value back to the one saved under `name` and returns it.
+    ///
+    /// The savepoint is kept, so it can be restored again. Like other operations, restoring
+    /// records the previous value so it can be undone.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyKeyError` if no savepoint named `name` exists.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(2.0)
+    /// calc.save("before")
+    /// calc.multiply(10.0)
+    /// calc.restore("before")  # 2.0
+    /// ```
+    fn restore(&mut self, name: &str) -> PyResult<f64> {
+        let saved = *self
+            .savepoints
+            .get(name)
+            .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(name.to_string()))?;
+        self.record_history();
+        self.value = saved;
+        Ok(self.value)
+    }
+
+    /// Returns the names of all savepoints in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc.savepoints()  # ["after", "before"]
+    /// ```
+    fn savepoints(&self) -> Vec<String> {
+        self.savepoints.keys().cloned().collect()
+    }
+
+    /// Returns an immutable copy of the current value, history, samples, and savepoints.
+    ///
+    /// Later operations on the calculator do not affect the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(5.0)
+    /// snap = calc.snapshot()
+    /// calc.add(1.0)
+    /// snap.value  # 5.0
+    /// ```
+    fn snapshot(&self) -> CalculatorSnapshot {
+        CalculatorSnapshot {
+            value: self.value,
+            history: self.history.clone(),
+            samples: self.samples.clone(),
+            savepoints: self.savepoints.clone(),
+        }
     }
 
     /// Adds `x` to the calculator's internal value and returns the updated total.
     ///
+    /// # Errors
+    ///
+    /// In strict mode, returns a `PyValueError` if the result is not finite.
+    ///
     /// # Examples
     ///
     /// ```ignore
     /// c = Calculator(1.5)
     /// c.add(2.0)
     /// ```
-    fn add(&mut self, x: f64) -> f64 {
-        self.value += x;
-        self.value
+    fn add(&mut self, x: f64) -> PyResult<f64> {
+        self.apply(self.value + x)
     }
 
     /// Multiplies the calculator's internal value by the given factor.
@@ -46,9 +481,73 @@ impl Calculator {
     /// ```
     /// # Returns
     /// `f64` — the updated internal value after multiplication.
-    fn multiply(&mut self, x: f64) -> f64 {
-        self.value *= x;
-        self.value
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, returns a `PyValueError` if the result is not finite.
+    fn multiply(&mut self, x: f64) -> PyResult<f64> {
+        self.apply(self.value * x)
+    }
+
+    /// Divides the calculator's internal value by `x` and returns the updated value.
+    ///
+    /// Division by zero follows IEEE 754 and yields infinity or NaN unless strict mode is on.
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, returns a `PyValueError` if the result is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(9.0)
+    /// calc.divide(3.0)
+    /// ```
+    fn divide(&mut self, x: f64) -> PyResult<f64> {
+        self.apply(self.value / x)
+    }
+
+    /// Applies `op` with each of `values` in turn, starting from the current value, and returns
+    /// the result after every step.
+    ///
+    /// `op` is one of `"add"`, `"multiply"`, or `"divide"`. The last result becomes the new
+    /// value, recorded in the history as a single operation; an empty `values` changes nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` for an unknown `op`, or in strict mode if any step produces a
+    /// non-finite result. The value is left untouched on error.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(0.0)
+    /// calc.accumulate([1.0, 2.0, 3.0], "add")  # [1.0, 3.0, 6.0]
+    /// calc.value  # 6.0
+    /// ```
+    fn accumulate(&mut self, values: Vec<f64>, op: &str) -> PyResult<Vec<f64>> {
+        let step: fn(f64, f64) -> f64 = match op {
+            "add" => |acc, x| acc + x,
+            "multiply" => |acc, x| acc * x,
+            "divide" => |acc, x| acc / x,
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "unsupported accumulate op '{}'",
+                    op
+                )))
+            }
+        };
+        let mut acc = self.value;
+        let mut results = Vec::with_capacity(values.len());
+        for x in values {
+            acc = step(acc, x);
+            self.check_strict(acc)?;
+            results.push(acc);
+        }
+        if let Some(&last) = results.last() {
+            self.apply(last)?;
+        }
+        Ok(results)
     }
 
     /// Resets the calculator's internal value to 0.0 and returns the new value.
@@ -64,10 +563,609 @@ impl Calculator {
     /// calc.reset()
     /// ```
     fn reset(&mut self) -> f64 {
+        self.record_history();
         self.value = 0.0;
         self.value
     }
 
+    /// Flips the sign of the value and returns the updated value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(-4.0).negate()  # 4.0
+    /// ```
+    fn negate(&mut self) -> f64 {
+        self.record_history();
+        self.value = -self.value;
+        self.value
+    }
+
+    /// Replaces the value with its absolute value and returns the updated value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(-4.0).abs()  # 4.0
+    /// ```
+    fn abs(&mut self) -> f64 {
+        self.record_history();
+        self.value = self.value.abs();
+        self.value
+    }
+
+    /// Treats the current value as degrees, converts it to radians, and returns the updated value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(180.0)
+    /// calc.to_radians()  # 3.141592653589793
+    /// ```
+    #[pyo3(name = "to_radians")]
+    fn convert_to_radians(&mut self) -> PyResult<f64> {
+        self.apply(self.value.to_radians())
+    }
+
+    /// Treats the current value as radians, converts it to degrees, and returns the updated value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(math.pi)
+    /// calc.to_degrees()  # 180.0
+    /// ```
+    #[pyo3(name = "to_degrees")]
+    fn convert_to_degrees(&mut self) -> PyResult<f64> {
+        self.apply(self.value.to_degrees())
+    }
+
+    /// Grows the value by compound interest over `periods` periods and returns the result.
+    ///
+    /// `rate` is the fractional rate per period, so `0.05` means 5% per period. The new value is
+    /// `value * (1 + rate) ** periods`; a negative rate down to `-1.0` models a loss per period.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `rate` is below `-1.0` or is NaN, or in strict mode if the
+    /// result is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(1000.0)
+    /// calc.compound_interest(0.05, 10)  # 1628.89...
+    /// ```
+    fn compound_interest(&mut self, rate: f64, periods: u32) -> PyResult<f64> {
+        if rate < -1.0 || rate.is_nan() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "rate must be at least -1.0, got {}",
+                rate
+            )));
+        }
+        // `powi` takes an i32, so larger period counts fall back to `powf`.
+        let growth = match i32::try_from(periods) {
+            Ok(periods) => (1.0 + rate).powi(periods),
+            Err(_) => (1.0 + rate).powf(periods as f64),
+        };
+        self.apply(self.value * growth)
+    }
+
+    /// Replaces the value with its natural logarithm and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the value is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(math.e)
+    /// calc.ln()  # 1.0
+    /// ```
+    fn ln(&mut self) -> PyResult<f64> {
+        let v = self.log_domain_value()?;
+        self.apply(v.ln())
+    }
+
+    /// Replaces the value with its base-10 logarithm and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the value is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(1000.0)
+    /// calc.log10()  # 3.0
+    /// ```
+    fn log10(&mut self) -> PyResult<f64> {
+        let v = self.log_domain_value()?;
+        self.apply(v.log10())
+    }
+
+    /// Replaces the value with its logarithm in the given `base` and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the value is not positive, or if `base` is not positive or is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(8.0)
+    /// calc.log(2.0)  # 3.0
+    /// ```
+    fn log(&mut self, base: f64) -> PyResult<f64> {
+        if base <= 0.0 || base.is_nan() || base == 1.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "logarithm base must be positive and not 1, got {}",
+                base
+            )));
+        }
+        let v = self.log_domain_value()?;
+        self.apply(v.log(base))
+    }
+
+    /// Replaces the value with `e` raised to it and returns the result.
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, returns a `PyValueError` if the result overflows to infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(1.0)
+    /// calc.exp()  # 2.718281828459045
+    /// ```
+    fn exp(&mut self) -> PyResult<f64> {
+        self.apply(self.value.exp())
+    }
+
+    /// Replaces the value with the bitwise AND of its integer form and `x`.
+    ///
+    /// The bitwise methods operate on the value as an `i64` and store the result back as a float,
+    /// so results beyond 2^53 may not be exactly representable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the current value is not an exact integer.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(6.0).bit_and(3)  # 2.0
+    /// ```
+    fn bit_and(&mut self, x: i64) -> PyResult<f64> {
+        let v = self.integral_value("bit_and")?;
+        self.apply((v & x) as f64)
+    }
+
+    /// Replaces the value with the bitwise OR of its integer form and `x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the current value is not an exact integer.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(6.0).bit_or(3)  # 7.0
+    /// ```
+    fn bit_or(&mut self, x: i64) -> PyResult<f64> {
+        let v = self.integral_value("bit_or")?;
+        self.apply((v | x) as f64)
+    }
+
+    /// Replaces the value with the bitwise XOR of its integer form and `x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the current value is not an exact integer.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(6.0).bit_xor(3)  # 5.0
+    /// ```
+    fn bit_xor(&mut self, x: i64) -> PyResult<f64> {
+        let v = self.integral_value("bit_xor")?;
+        self.apply((v ^ x) as f64)
+    }
+
+    /// Shifts the integer form of the value left by `bits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the current value is not an exact integer or `bits` is not in `0..64`,
+    /// and a `PyOverflowError` if the shift would push set bits out of an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(3.0).shift_left(2)  # 12.0
+    /// ```
+    fn shift_left(&mut self, bits: i64) -> PyResult<f64> {
+        let v = self.integral_value("shift_left")?;
+        let bits = Self::shift_amount(bits)?;
+        let shifted = v << bits;
+        if shifted >> bits != v {
+            return Err(pyo3::exceptions::PyOverflowError::new_err("shift_left overflow"));
+        }
+        self.apply(shifted as f64)
+    }
+
+    /// Arithmetically shifts the integer form of the value right by `bits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the current value is not an exact integer or `bits` is not in `0..64`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(12.0).shift_right(2)  # 3.0
+    /// ```
+    fn shift_right(&mut self, bits: i64) -> PyResult<f64> {
+        let v = self.integral_value("shift_right")?;
+        let bits = Self::shift_amount(bits)?;
+        self.apply((v >> bits) as f64)
+    }
+
+    /// Returns the greatest common divisor of the value and `other`, leaving the value unchanged.
+    ///
+    /// The result is never negative, and `gcd(0, 0)` is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the current value is not an exact integer, or a
+    /// `PyOverflowError` if the result (only possible for `-2**63`) does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(12.0).gcd(18)  # 6
+    /// ```
+    fn gcd(&self, other: i64) -> PyResult<i64> {
+        let v = self.integral_value("gcd")?;
+        i64::try_from(Self::gcd_u64(v.unsigned_abs(), other.unsigned_abs()))
+            .map_err(|_| pyo3::exceptions::PyOverflowError::new_err("gcd overflow"))
+    }
+
+    /// Returns the least common multiple of the value and `other`, leaving the value unchanged.
+    ///
+    /// The result is never negative, and is 0 if either operand is 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the current value is not an exact integer, or a
+    /// `PyOverflowError` if the result does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(4.0).lcm(6)  # 12
+    /// ```
+    fn lcm(&self, other: i64) -> PyResult<i64> {
+        let (a, b) = (self.integral_value("lcm")?.unsigned_abs(), other.unsigned_abs());
+        if a == 0 || b == 0 {
+            return Ok(0);
+        }
+        (a / Self::gcd_u64(a, b))
+            .checked_mul(b)
+            .and_then(|lcm| i64::try_from(lcm).ok())
+            .ok_or_else(|| pyo3::exceptions::PyOverflowError::new_err("lcm overflow"))
+    }
+
+    /// Returns the fraction `(numerator, denominator)` closest to the value among those whose
+    /// denominator is at most `max_denominator`, leaving the value unchanged.
+    ///
+    /// The approximation walks the continued-fraction expansion of the exact binary value, as Python's
+    /// `Fraction.limit_denominator` does. The denominator is always positive and the sign is
+    /// carried by the numerator.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the value is not finite or `max_denominator` is 0, or a
+    /// `PyOverflowError` if the numerator or denominator does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(0.5).to_fraction(1000)  # (1, 2)
+    /// Calculator(1 / 3).to_fraction(10)  # (1, 3)
+    /// Calculator(3.14159).to_fraction(100)  # (311, 99)
+    /// ```
+    fn to_fraction(&self, max_denominator: u64) -> PyResult<(i64, i64)> {
+        if !self.value.is_finite() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "cannot convert {} to a fraction",
+                self.value
+            )));
+        }
+        if max_denominator == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err("max_denominator must be at least 1"));
+        }
+        if self.value.abs() >= i64::MAX as f64 {
+            return Err(pyo3::exceptions::PyOverflowError::new_err("fraction overflow"));
+        }
+        let Some((mut n, mut d)) = Self::exact_ratio(self.value) else {
+            // Smaller in magnitude than 2^-74, so nearer to 0 than to any 1/q with q <= u64::MAX.
+            return Ok((0, 1));
+        };
+
+        let overflow = || pyo3::exceptions::PyOverflowError::new_err("fraction overflow");
+        let max = i128::from(max_denominator);
+        // Two most recent convergents p0/q0 and p1/q1, seeded with 0/1 and 1/0.
+        let (mut p0, mut q0, mut p1, mut q1) = (0i128, 1i128, 1i128, 0i128);
+        loop {
+            let term = n.div_euclid(d);
+            match term.checked_mul(q1).and_then(|t| t.checked_add(q0)) {
+                Some(q2) if q2 <= max => {
+                    let p2 = term.checked_mul(p1).and_then(|t| t.checked_add(p0)).ok_or_else(overflow)?;
+                    (p0, q0, p1, q1) = (p1, q1, p2, q2);
+                }
+                _ => break,
+            }
+            (n, d) = (d, n - term * d);
+            if d == 0 {
+                break;
+            }
+        }
+
+        if d != 0 {
+            // The next convergent's denominator is too large. The best semiconvergent within the bound
+            // is (p0 + k*p1) / (q0 + k*q1); with n/d the remaining complete quotient, the last
+            // convergent is at least as close exactly when (q0 + k*q1) / q1 <= (n - k*d) / d.
+            let k = (max - q0) / q1;
+            let (p_semi, q_semi) = (p0 + k * p1, q0 + k * q1);
+            let semi_wins = Self::cmp_ratio(q_semi as u128, q1 as u128, (n - k * d) as u128, d as u128)
+                == std::cmp::Ordering::Greater;
+            if semi_wins {
+                (p1, q1) = (p_semi, q_semi);
+            }
+        }
+        Ok((
+            i64::try_from(p1).map_err(|_| overflow())?,
+            i64::try_from(q1).map_err(|_| overflow())?,
+        ))
+    }
+
+    /// Appends `x` to the sample dataset used by `mean`, `median`, and `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(0.0)
+    /// calc.push(1.0)
+    /// calc.push(2.0)
+    /// ```
+    fn push(&mut self, x: f64) {
+        self.samples.push(x);
+    }
+
+    /// Returns the arithmetic mean of the pushed samples.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if no samples have been pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc.mean()
+    /// ```
+    fn mean(&self) -> PyResult<f64> {
+        let samples = self.require_samples()?;
+        Ok(samples.iter().sum::<f64>() / samples.len() as f64)
+    }
+
+    /// Returns the median of the pushed samples.
+    ///
+    /// With an even number of samples, the two middle values are averaged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if no samples have been pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for x in [4.0, 1.0, 3.0, 2.0]:
+    ///     calc.push(x)
+    /// calc.median()  # 2.5
+    /// ```
+    fn median(&self) -> PyResult<f64> {
+        let mut sorted = self.require_samples()?.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let mid = sorted.len() / 2;
+        if sorted.len() % 2 == 0 {
+            Ok((sorted[mid - 1] + sorted[mid]) / 2.0)
+        } else {
+            Ok(sorted[mid])
+        }
+    }
+
+    /// Returns the most frequent pushed sample.
+    ///
+    /// Samples are compared exactly; when several values are equally frequent, the smallest wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if no samples have been pushed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for x in [1.0, 2.0, 2.0, 3.0]:
+    ///     calc.push(x)
+    /// calc.mode()  # 2.0
+    /// ```
+    fn mode(&self) -> PyResult<f64> {
+        let mut sorted = self.require_samples()?.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let (mut best, mut best_count) = (sorted[0], 0);
+        for run in sorted.chunk_by(|a, b| a.total_cmp(b).is_eq()) {
+            if run.len() > best_count {
+                best = run[0];
+                best_count = run.len();
+            }
+        }
+        Ok(best)
+    }
+
+    /// Returns the simple moving average of the pushed samples over a sliding `window`.
+    ///
+    /// The result has `len(samples) - window + 1` entries, the first averaging samples
+    /// `0..window`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `window` is 0 or larger than the number of samples.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for x in [1.0, 2.0, 3.0, 4.0, 5.0]:
+    ///     calc.push(x)
+    /// calc.moving_average(3)  # [2.0, 3.0, 4.0]
+    /// ```
+    fn moving_average(&self, window: usize) -> PyResult<Vec<f64>> {
+        if window == 0 || window > self.samples.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "window must be between 1 and the sample count ({}), got {}",
+                self.samples.len(),
+                window
+            )));
+        }
+        Ok(self
+            .samples
+            .windows(window)
+            .map(|w| w.iter().sum::<f64>() / window as f64)
+            .collect())
+    }
+
+    /// Serializes the calculator's state to a compact JSON string so a session can be saved.
+    ///
+    /// NaN and infinite values are written as the strings `"NaN"`, `"Infinity"`, and
+    /// `"-Infinity"` so that `from_json` restores them exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator(3.5)
+    /// calc.to_json()  # '{"value":3.5,"strict":false,"samples":[],"history":[],"history_limit":null,"savepoints":{},"eq_epsilon":null}'
+    /// ```
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Restores a Calculator from a JSON string produced by `to_json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `json_str` is not valid JSON or does not match the Calculator state,
+    /// prefixed with the path of the failing field, or if `eq_epsilon` is negative or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// calc = Calculator.from_json('{"value":3.5}')
+    /// calc.value
+    /// ```
+    #[staticmethod]
+    fn from_json(json_str: String) -> PyResult<Self> {
+        parse_json(&json_str)
+    }
+
+    /// Returns a copy of the calculator with the value negated, leaving this one unchanged.
+    ///
+    /// The copy keeps the rest of the state, and its history ends with the original value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// (-Calculator(-4.0)).value  # 4.0
+    /// ```
+    fn __neg__(&self) -> Self {
+        let mut negated = self.clone();
+        negated.negate();
+        negated
+    }
+
+    /// Returns a copy of the calculator holding the absolute value, leaving this one unchanged.
+    ///
+    /// As with `-calc`, the copy keeps the rest of the state.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// abs(Calculator(-4.0)).value  # 4.0
+    /// ```
+    fn __abs__(&self) -> Self {
+        let mut absolute = self.clone();
+        absolute.abs();
+        absolute
+    }
+
+    /// Returns `False` when the calculator's value is zero, so `if calc:` tests for a non-zero value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// bool(Calculator(0.0))  # False
+    /// bool(Calculator(2.5))  # True
+    /// ```
+    fn __bool__(&self) -> bool {
+        self.value != 0.0
+    }
+
+    /// Compares the calculator's value against another Calculator or a plain number.
+    ///
+    /// Returns `NotImplemented` for any other right-hand side so Python can try the reflected operation.
+    /// When `eq_epsilon` is set, `==` and `!=` treat values within that tolerance as equal;
+    /// ordering comparisons are always exact. Against another Calculator the larger of the two
+    /// tolerances applies, so `a == b` and `b == a` always agree.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Calculator(3.0) < 5.0                    # True
+    /// Calculator(3.0) == Calculator(3.0)       # True
+    /// Calculator(0.1 + 0.2) == 0.3             # False
+    /// Calculator(0.1 + 0.2, eq_epsilon=1e-9) == 0.3  # True
+    /// ```
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<Py<PyAny>> {
+        let py = other.py();
+        let (rhs, epsilon) = if let Ok(calc) = other.cast::<Calculator>() {
+            let calc = calc.borrow();
+            (calc.value, self.eq_epsilon.into_iter().chain(calc.eq_epsilon).reduce(f64::max))
+        } else if let Ok(x) = other.extract::<f64>() {
+            (x, self.eq_epsilon)
+        } else {
+            return Ok(py.NotImplemented());
+        };
+        let equal = match epsilon {
+            Some(epsilon) => (self.value - rhs).abs() <= epsilon,
+            None => self.value == rhs,
+        };
+        let result = match op {
+            CompareOp::Lt => self.value < rhs,
+            CompareOp::Le => self.value <= rhs,
+            CompareOp::Eq => equal,
+            CompareOp::Ne => !equal,
+            CompareOp::Gt => self.value > rhs,
+            CompareOp::Ge => self.value >= rhs,
+        };
+        Ok(result.into_pyobject(py)?.to_owned().into_any().unbind())
+    }
+
     /// Formats a string representation of the calculator including its current value.
     ///
     /// # Returns
@@ -84,3 +1182,375 @@ impl Calculator {
         format!("Calculator(value={})", self.value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{message, with_py};
+
+    fn calc(value: f64) -> Calculator {
+        Calculator::new(value, false, None, None).unwrap()
+    }
+
+    #[test]
+    fn json_round_trip_preserves_value() {
+        let mut original = calc(1.0);
+        original.add(2.0).unwrap();
+        original.multiply(4.0).unwrap();
+        original.push(1.5);
+        original.save("start".to_string());
+
+        let restored = Calculator::from_json(original.to_json().unwrap()).unwrap();
+        assert_eq!(restored.value, 12.0);
+        assert_eq!(restored.history, [1.0, 3.0]);
+        assert_eq!(restored.samples, [1.5]);
+        assert_eq!(restored.savepoints, original.savepoints);
+    }
+
+    #[test]
+    fn json_round_trip_preserves_non_finite_floats() {
+        let mut original = calc(f64::INFINITY);
+        let json = original.to_json().unwrap();
+        assert!(json.contains(r#""value":"Infinity""#), "{}", json);
+        assert_eq!(Calculator::from_json(json).unwrap().value, f64::INFINITY);
+
+        original.value = f64::NAN;
+        assert!(Calculator::from_json(original.to_json().unwrap()).unwrap().value.is_nan());
+    }
+
+    #[test]
+    fn from_json_fills_defaults_and_reports_bad_fields() {
+        let restored = Calculator::from_json(r#"{"value":3}"#.to_string()).unwrap();
+        assert_eq!(restored.value, 3.0);
+        assert!(!restored.strict && restored.history.is_empty() && restored.eq_epsilon.is_none());
+
+        let err = Calculator::from_json(r#"{"value":"big"}"#.to_string()).err().unwrap();
+        assert!(message(err).starts_with("value: invalid value"));
+    }
+
+    #[test]
+    fn converts_between_degrees_and_radians() {
+        let mut c = calc(180.0);
+        assert!((c.convert_to_radians().unwrap() - std::f64::consts::PI).abs() < 1e-12);
+        assert!((c.convert_to_degrees().unwrap() - 180.0).abs() < 1e-12);
+        assert_eq!(c.history.len(), 2);
+    }
+
+    #[test]
+    fn strict_mode_rejects_inf_and_nan_results() {
+        let mut strict = Calculator::new(1.0, true, None, None).unwrap();
+        assert!(strict.divide(0.0).is_err());
+        assert_eq!(strict.value, 1.0);
+        assert!(strict.history.is_empty());
+
+        strict.value = f64::INFINITY;
+        assert!(strict.multiply(0.0).is_err());
+        assert_eq!(strict.value, f64::INFINITY);
+
+        let mut lenient = calc(1.0);
+        assert_eq!(lenient.divide(0.0).unwrap(), f64::INFINITY);
+        assert!(lenient.multiply(0.0).unwrap().is_nan());
+    }
+
+    #[test]
+    fn compares_against_floats_and_calculators() {
+        with_py(|py| {
+            let three = Bound::new(py, calc(3.0)).unwrap();
+            let five = Bound::new(py, calc(5.0)).unwrap();
+            assert!(three.lt(5.0).unwrap());
+            assert!(three.ge(3.0).unwrap());
+            assert!(three.eq(3.0).unwrap());
+            assert!(three.lt(&five).unwrap());
+            assert!(five.gt(&three).unwrap());
+            assert!(three.ne(&five).unwrap());
+            assert!(three.lt("five").is_err());
+        });
+    }
+
+    #[test]
+    fn statistics_over_odd_and_even_sample_counts() {
+        let mut odd = calc(0.0);
+        for x in [3.0, 1.0, 2.0, 2.0, 7.0] {
+            odd.push(x);
+        }
+        assert_eq!(odd.mean().unwrap(), 3.0);
+        assert_eq!(odd.median().unwrap(), 2.0);
+        assert_eq!(odd.mode().unwrap(), 2.0);
+
+        let mut even = calc(0.0);
+        for x in [4.0, 1.0, 3.0, 2.0] {
+            even.push(x);
+        }
+        assert_eq!(even.mean().unwrap(), 2.5);
+        assert_eq!(even.median().unwrap(), 2.5);
+    }
+
+    #[test]
+    fn statistics_require_samples() {
+        assert!(calc(0.0).mean().is_err());
+        assert!(calc(0.0).median().is_err());
+        assert!(calc(0.0).mode().is_err());
+    }
+
+    #[test]
+    fn zero_calculator_is_falsy() {
+        assert!(!calc(0.0).__bool__());
+        assert!(calc(-2.5).__bool__());
+    }
+
+    #[test]
+    fn history_limit_drops_oldest_entries() {
+        let mut c = Calculator::new(0.0, false, Some(2), None).unwrap();
+        for _ in 0..3 {
+            c.add(1.0).unwrap();
+        }
+        assert_eq!(c.history, [1.0, 2.0]);
+        assert_eq!(c.undo().unwrap(), 2.0);
+        assert_eq!(c.undo().unwrap(), 1.0);
+        assert!(c.undo().is_err());
+
+        c.history = vec![1.0, 2.0, 3.0];
+        c.set_history_limit(Some(1));
+        assert_eq!(c.history, [3.0]);
+    }
+
+    #[test]
+    fn moving_average_over_window_of_three() {
+        let mut c = calc(0.0);
+        for x in [1.0, 2.0, 3.0, 4.0, 8.0] {
+            c.push(x);
+        }
+        assert_eq!(c.moving_average(3).unwrap(), [2.0, 3.0, 5.0]);
+        assert_eq!(c.moving_average(5).unwrap().len(), 1);
+        assert_eq!(
+            message(c.moving_average(6).unwrap_err()),
+            "window must be between 1 and the sample count (5), got 6"
+        );
+        assert!(c.moving_average(0).is_err());
+    }
+
+    #[test]
+    fn bitwise_ops_on_integral_values() {
+        let mut c = calc(6.0);
+        assert_eq!(c.bit_and(3).unwrap(), 2.0);
+        assert_eq!(c.bit_or(5).unwrap(), 7.0);
+        assert_eq!(c.bit_xor(1).unwrap(), 6.0);
+        assert_eq!(c.shift_left(2).unwrap(), 24.0);
+        assert_eq!(c.shift_right(3).unwrap(), 3.0);
+        assert!(c.shift_left(64).is_err());
+    }
+
+    #[test]
+    fn shift_left_rejects_shifts_that_lose_bits() {
+        let mut c = calc(3.0);
+        assert_eq!(message(c.shift_left(63).unwrap_err()), "shift_left overflow");
+        assert_eq!(c.value, 3.0);
+        assert_eq!(calc(1.0).shift_left(62).unwrap(), 4611686018427387904.0);
+        assert_eq!(calc(-1.0).shift_left(63).unwrap(), i64::MIN as f64);
+    }
+
+    #[test]
+    fn bitwise_ops_reject_non_integral_values() {
+        let mut c = calc(2.5);
+        assert_eq!(message(c.bit_and(3).unwrap_err()), "bit_and requires an integral value, got 2.5");
+        assert_eq!(c.value, 2.5);
+        assert!(calc(f64::NAN).bit_or(1).is_err());
+        assert!(calc(1e19).bit_xor(1).is_err());
+    }
+
+    #[test]
+    fn save_mutate_and_restore() {
+        with_py(|py| {
+            let mut c = calc(5.0);
+            c.save("start".to_string());
+            c.multiply(3.0).unwrap();
+            c.save("tripled".to_string());
+            c.add(1.0).unwrap();
+            assert_eq!(c.savepoints(), ["start", "tripled"]);
+
+            assert_eq!(c.restore("start").unwrap(), 5.0);
+            assert_eq!(c.undo().unwrap(), 16.0);
+            assert!(c.restore("missing").unwrap_err().is_instance_of::<pyo3::exceptions::PyKeyError>(py));
+        });
+    }
+
+    #[test]
+    fn epsilon_equality_tolerates_rounding() {
+        with_py(|py| {
+            let exact = Bound::new(py, calc(0.1 + 0.2)).unwrap();
+            let tolerant = Bound::new(py, Calculator::new(0.1 + 0.2, false, None, Some(1e-9)).unwrap()).unwrap();
+            assert!(!exact.eq(0.3).unwrap());
+            assert!(exact.ne(0.3).unwrap());
+            assert!(tolerant.eq(0.3).unwrap());
+            assert!(!tolerant.ne(0.3).unwrap());
+            assert!(!tolerant.lt(0.3).unwrap() && tolerant.gt(0.3).unwrap());
+        });
+    }
+
+    #[test]
+    fn epsilon_equality_between_calculators_is_symmetric() {
+        with_py(|py| {
+            let exact = Bound::new(py, calc(0.3)).unwrap();
+            let tolerant = Bound::new(py, Calculator::new(0.1 + 0.2, false, None, Some(1e-9)).unwrap()).unwrap();
+            assert!(tolerant.eq(&exact).unwrap());
+            assert!(exact.eq(&tolerant).unwrap());
+            assert!(!exact.ne(&tolerant).unwrap());
+        });
+    }
+
+    #[test]
+    fn eq_epsilon_must_be_non_negative() {
+        assert_eq!(
+            message(Calculator::new(0.0, false, None, Some(-1.0)).err().unwrap()),
+            "eq_epsilon must be a non-negative number, got -1"
+        );
+        assert!(Calculator::new(0.0, false, None, Some(f64::NAN)).is_err());
+
+        let mut c = calc(0.0);
+        assert!(c.set_eq_epsilon(Some(-0.5)).is_err());
+        assert_eq!(c.eq_epsilon, None);
+        c.set_eq_epsilon(Some(0.0)).unwrap();
+        assert_eq!(c.eq_epsilon, Some(0.0));
+
+        assert!(Calculator::from_json(r#"{"value":1.0,"eq_epsilon":-1.0}"#.to_string()).is_err());
+        assert!(Calculator::from_json(r#"{"value":1.0,"eq_epsilon":"NaN"}"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn logarithms_and_exp() {
+        assert!((calc(std::f64::consts::E).ln().unwrap() - 1.0).abs() < 1e-12);
+        assert!((calc(1000.0).log10().unwrap() - 3.0).abs() < 1e-12);
+        assert!((calc(8.0).log(2.0).unwrap() - 3.0).abs() < 1e-12);
+        assert!((calc(1.0).exp().unwrap() - std::f64::consts::E).abs() < 1e-12);
+    }
+
+    #[test]
+    fn logarithms_reject_domain_errors() {
+        let mut c = calc(-1.0);
+        assert!(c.ln().is_err());
+        assert!(calc(0.0).log10().is_err());
+        assert_eq!(c.value, -1.0);
+        assert_eq!(message(calc(10.0).log(1.0).unwrap_err()), "logarithm base must be positive and not 1, got 1");
+        assert!(calc(10.0).log(-2.0).is_err());
+    }
+
+    #[test]
+    fn accumulate_returns_running_totals() {
+        let mut c = calc(10.0);
+        assert_eq!(c.accumulate(vec![1.0, 2.0, 3.0], "add").unwrap(), [11.0, 13.0, 16.0]);
+        assert_eq!(c.value, 16.0);
+        assert_eq!(c.undo().unwrap(), 10.0);
+        assert_eq!(c.accumulate(vec![2.0, 0.5], "multiply").unwrap(), [20.0, 10.0]);
+    }
+
+    #[test]
+    fn accumulate_rejects_unknown_ops_and_strict_overflow() {
+        let mut c = calc(1.0);
+        assert_eq!(message(c.accumulate(vec![1.0], "subtract").unwrap_err()), "unsupported accumulate op 'subtract'");
+
+        let mut strict = Calculator::new(1.0, true, None, None).unwrap();
+        assert!(strict.accumulate(vec![2.0, 0.0], "divide").is_err());
+        assert_eq!(strict.value, 1.0);
+    }
+
+    #[test]
+    fn compound_interest_over_ten_periods() {
+        let mut c = calc(1000.0);
+        let result = c.compound_interest(0.05, 10).unwrap();
+        assert!((result - 1628.894626777442).abs() < 1e-9, "{}", result);
+        assert_eq!(c.value, result);
+        assert_eq!(calc(1000.0).compound_interest(0.05, 0).unwrap(), 1000.0);
+        assert_eq!(message(calc(1000.0).compound_interest(-1.5, 1).unwrap_err()), "rate must be at least -1.0, got -1.5");
+    }
+
+    #[test]
+    fn gcd_and_lcm_of_integral_values() {
+        assert_eq!(calc(12.0).gcd(18).unwrap(), 6);
+        assert_eq!(calc(-12.0).gcd(18).unwrap(), 6);
+        assert_eq!(calc(0.0).gcd(0).unwrap(), 0);
+        assert_eq!(calc(4.0).lcm(6).unwrap(), 12);
+        assert_eq!(calc(0.0).lcm(6).unwrap(), 0);
+        assert!(calc(1.5).gcd(3).is_err());
+    }
+
+    #[test]
+    fn gcd_and_lcm_raise_on_overflow() {
+        with_py(|py| {
+            let big = calc(4_611_686_018_427_387_904.0); // 2^62
+            assert!(big.lcm(3).unwrap_err().is_instance_of::<pyo3::exceptions::PyOverflowError>(py));
+            let min = calc(i64::MIN as f64);
+            assert!(min.gcd(0).unwrap_err().is_instance_of::<pyo3::exceptions::PyOverflowError>(py));
+        });
+    }
+
+    #[test]
+    fn exact_ratio_is_the_binary_value() {
+        assert_eq!(Calculator::exact_ratio(0.0), Some((0, 1)));
+        assert_eq!(Calculator::exact_ratio(-0.75), Some((-3, 4)));
+        assert_eq!(Calculator::exact_ratio(6.0), Some((6, 1)));
+        assert_eq!(Calculator::exact_ratio(0.1), Some((3602879701896397, 36028797018963968)));
+        assert_eq!(Calculator::exact_ratio(1e-300), None);
+    }
+
+    #[test]
+    fn to_fraction_matches_limit_denominator() {
+        // Expected values from Python's `Fraction(value).limit_denominator(max_denominator)`.
+        let cases = [
+            (0.5, 1000, (1, 2)),
+            (1.0 / 3.0, 10, (1, 3)),
+            (0.3, 3, (1, 3)),
+            (2.35714, 100, (33, 14)),
+            (-0.618034, 50, (-21, 34)),
+            (123456.789, 7, (617284, 5)),
+            (1e-30, 1_000_000, (0, 1)),
+            (0.1, u64::MAX, (3602879701896397, 36028797018963968)),
+        ];
+        for (value, max_denominator, expected) in cases {
+            assert_eq!(calc(value).to_fraction(max_denominator).unwrap(), expected, "{} / {}", value, max_denominator);
+        }
+    }
+
+    #[test]
+    fn to_fraction_rejects_non_finite_values() {
+        assert_eq!(message(calc(f64::NAN).to_fraction(10).unwrap_err()), "cannot convert NaN to a fraction");
+        assert!(calc(f64::INFINITY).to_fraction(10).is_err());
+        assert!(calc(0.5).to_fraction(0).is_err());
+        assert!(calc(1e19).to_fraction(10).is_err());
+    }
+
+    #[test]
+    fn snapshot_is_unaffected_by_later_mutation() {
+        let mut calc = calc(5.0);
+        calc.add(1.0).unwrap();
+        calc.push(2.0);
+        calc.save("start".to_string());
+        let snapshot = calc.snapshot();
+        calc.multiply(10.0).unwrap();
+        calc.push(3.0);
+        calc.save("later".to_string());
+        assert_eq!(snapshot.value, 6.0);
+        assert_eq!(snapshot.history, vec![5.0]);
+        assert_eq!(snapshot.samples, vec![2.0]);
+        assert_eq!(snapshot.savepoints.keys().collect::<Vec<_>>(), ["start"]);
+        assert_eq!(calc.value, 60.0);
+    }
+
+    #[test]
+    fn negate_and_abs_update_the_value() {
+        let mut calc = calc(-4.5);
+        assert_eq!(calc.negate(), 4.5);
+        assert_eq!(calc.history, vec![-4.5]);
+        calc.value = -3.0;
+        assert_eq!(calc.abs(), 3.0);
+        assert_eq!(calc.abs(), 3.0);
+    }
+
+    #[test]
+    fn neg_and_abs_dunders_return_copies() {
+        let original = calc(-2.0);
+        assert_eq!(original.__neg__().value, 2.0);
+        assert_eq!(original.__abs__().value, 2.0);
+        assert_eq!(original.value, -2.0);
+        assert!(original.history.is_empty());
+    }
+}
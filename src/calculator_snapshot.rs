@@ -0,0 +1,36 @@
+use pyo3::prelude::*;
+use std::collections::BTreeMap;
+
+/// A read-only copy of a Calculator's state, taken by `Calculator.snapshot`.
+///
+/// The class is frozen and exposes only getters, so a stored snapshot never changes,
+/// whatever happens to the calculator it was taken from.
+#[pyclass(frozen, skip_from_py_object)]
+#[derive(Clone)]
+pub struct CalculatorSnapshot {
+    #[pyo3(get)]
+    pub value: f64,
+    /// Previous values, oldest first, as they stood when the snapshot was taken.
+    #[pyo3(get)]
+    pub history: Vec<f64>,
+    /// Samples accumulated with `push`.
+    #[pyo3(get)]
+    pub samples: Vec<f64>,
+    /// Named values stored by `save`.
+    #[pyo3(get)]
+    pub savepoints: BTreeMap<String, f64>,
+}
+
+#[pymethods]
+impl CalculatorSnapshot {
+    /// Formats a string representation of the snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// repr(Calculator(5.0).snapshot())  # "CalculatorSnapshot(value=5)"
+    /// ```
+    fn __repr__(&self) -> String {
+        format!("CalculatorSnapshot(value={})", self.value)
+    }
+}
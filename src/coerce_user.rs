@@ -0,0 +1,69 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::user::{parse_json, User};
+
+/// Converts a loosely-typed Python value into a User, dispatching on its runtime type.
+///
+/// Accepted inputs:
+/// - an existing `User`, which is copied
+/// - a `dict` with the same keys as `User.dict()` (a boolean `active` may stand in for `status`)
+/// - a JSON string accepted by `User.from_json`
+///
+/// # Errors
+///
+/// Returns a `PyTypeError` for any other input type, and a `PyValueError` if a dict or
+/// JSON string does not describe a valid user.
+///
+/// # Examples
+///
+/// ```ignore
+/// coerce_user({"id": 1, "name": "Alice", "email": "alice@example.com", "age": 30, "active": True})
+/// coerce_user('{"id":1,"name":"Alice","email":"alice@example.com","age":30,"status":"active"}')
+/// coerce_user(existing_user)
+/// ```
+#[pyfunction]
+pub fn coerce_user(obj: Bound<'_, PyAny>) -> PyResult<User> {
+    if let Ok(user) = obj.cast::<User>() {
+        Ok(user.borrow().clone())
+    } else if let Ok(dict) = obj.cast::<PyDict>() {
+        User::from_py_dict(dict)
+    } else if let Ok(json_str) = obj.cast::<PyString>() {
+        parse_json(json_str.to_str()?)
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "cannot coerce object of type '{}' into a User",
+            obj.get_type().name()?
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{message, user, with_py};
+
+    #[test]
+    fn accepts_users_dicts_and_json_strings() {
+        with_py(|py| {
+            let existing = Bound::new(py, user(1, "Alice", "alice@example.com", 30, true)).unwrap().into_any();
+            let dict = py
+                .eval(c"{'id': 2, 'name': 'Bob', 'email': 'bob@example.com', 'age': 25, 'active': False}", None, None)
+                .unwrap();
+            let json = PyString::new(py, r#"{"id":3,"name":"Carol","email":"c@x.com","age":41,"status":"deleted"}"#);
+
+            assert_eq!(coerce_user(existing).unwrap().name, "Alice");
+            assert_eq!(coerce_user(dict).unwrap().name, "Bob");
+            assert_eq!(coerce_user(json.into_any()).unwrap().name, "Carol");
+        });
+    }
+
+    #[test]
+    fn rejects_other_types() {
+        with_py(|py| {
+            let err = coerce_user(py.eval(c"42", None, None).unwrap()).err().unwrap();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+            assert_eq!(message(err), "cannot coerce object of type 'int' into a User");
+        });
+    }
+}
@@ -0,0 +1,153 @@
+use once_cell::sync::Lazy;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use regex::Regex;
+
+use crate::validate_email::STRICT_EMAIL_REGEX;
+
+/// Email-like substrings, using the same pattern as strict `validate_email`.
+static EMAIL_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(STRICT_EMAIL_REGEX).expect("email pattern is valid"));
+
+/// Phone-like candidates: digit groups, optionally led by `+`, joined by a single space, dot,
+/// or dash, where a group may be wrapped in parentheses. Candidates are then split and
+/// filtered by their digit count in `phone_spans`.
+static PHONE_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\+?(?:\(\d+\) ?|\d+[ .-])*\d+").expect("phone pattern is valid"));
+
+/// Fewest and most digits (per E.164) a phone-like match may contain.
+const MIN_PHONE_DIGITS: usize = 7;
+const MAX_PHONE_DIGITS: usize = 15;
+
+/// Counts the ASCII digits in `s`.
+fn digit_count(s: &str) -> usize {
+    s.bytes().filter(u8::is_ascii_digit).count()
+}
+
+/// Returns the byte spans of the phone numbers within one `PHONE_PATTERN` match.
+///
+/// Numbers separated by a single space, such as `555-123-4567 555-987-6543`, match as one
+/// candidate. The candidate is therefore cut at its spaces and the pieces are packed, left to
+/// right, into runs of at most `MAX_PHONE_DIGITS` digits, so a candidate that fits stays whole.
+/// Only runs with `MIN_PHONE_DIGITS` to `MAX_PHONE_DIGITS` digits are kept.
+fn phone_spans(candidate: regex::Match<'_>) -> Vec<(usize, usize)> {
+    let plausible = |digits: usize| (MIN_PHONE_DIGITS..=MAX_PHONE_DIGITS).contains(&digits);
+    let mut spans = Vec::new();
+    // The run being packed, as (start, end, digits).
+    let mut run: Option<(usize, usize, usize)> = None;
+    let mut start = candidate.start();
+    for piece in candidate.as_str().split(' ') {
+        let (end, digits) = (start + piece.len(), digit_count(piece));
+        run = match run {
+            Some((run_start, _, run_digits)) if run_digits + digits <= MAX_PHONE_DIGITS => {
+                Some((run_start, end, run_digits + digits))
+            }
+            finished => {
+                spans.extend(finished.filter(|&(_, _, d)| plausible(d)).map(|(s, e, _)| (s, e)));
+                Some((start, end, digits))
+            }
+        };
+        start = end + 1;
+    }
+    spans.extend(run.filter(|&(_, _, d)| plausible(d)).map(|(s, e, _)| (s, e)));
+    spans
+}
+
+/// Converts byte offsets from `regex` into character offsets, so spans can slice Python strings.
+fn char_span(text: &str, start: usize, end: usize) -> (usize, usize) {
+    let start_chars = text[..start].chars().count();
+    (start_chars, start_chars + text[start..end].chars().count())
+}
+
+/// Scans free text for email addresses and phone numbers.
+///
+/// Emails are found with the strict `validate_email` pattern. Phone numbers are runs of
+/// 7 to 15 digits, optionally led by `+` and broken up by single spaces, dots, or dashes,
+/// with groups optionally in parentheses; digits that are part of a detected email are not
+/// reported as a phone. Numbers on separate lines or separated by a single space are
+/// reported separately.
+/// This is a heuristic: other digit runs of that length, such as `2024-01-02`, are also
+/// reported as phones.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `text` - The text to scan
+///
+/// # Returns
+///
+/// A dictionary with `has_email` and `has_phone` flags, plus `emails` and `phones`, lists of
+/// `(start, end)` character spans so that `text[start:end]` is the match
+///
+/// # Examples
+///
+/// ```ignore
+/// contains_pii("reach me at alice@example.com")
+/// # {"has_email": True, "has_phone": False, "emails": [(12, 29)], "phones": []}
+/// ```
+#[pyfunction]
+pub fn contains_pii<'py>(py: Python<'py>, text: &str) -> PyResult<Bound<'py, PyDict>> {
+    let email_bytes: Vec<(usize, usize)> = EMAIL_PATTERN.find_iter(text).map(|m| (m.start(), m.end())).collect();
+    let phone_bytes: Vec<(usize, usize)> = PHONE_PATTERN
+        .find_iter(text)
+        .flat_map(phone_spans)
+        .filter(|&(start, end)| !email_bytes.iter().any(|&(e_start, e_end)| start < e_end && e_start < end))
+        .collect();
+
+    let to_chars = |spans: &[(usize, usize)]| -> Vec<(usize, usize)> {
+        spans.iter().map(|&(start, end)| char_span(text, start, end)).collect()
+    };
+    let dict = PyDict::new(py);
+    dict.set_item("has_email", !email_bytes.is_empty())?;
+    dict.set_item("has_phone", !phone_bytes.is_empty())?;
+    dict.set_item("emails", to_chars(&email_bytes))?;
+    dict.set_item("phones", to_chars(&phone_bytes))?;
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::with_py;
+
+    /// Returns the matched substrings of `key` ("emails" or "phones") in `text`.
+    fn matches(text: &str, key: &str) -> Vec<String> {
+        with_py(|py| {
+            let found = contains_pii(py, text).unwrap();
+            let spans: Vec<(usize, usize)> = found.get_item(key).unwrap().unwrap().extract().unwrap();
+            let chars: Vec<char> = text.chars().collect();
+            spans.into_iter().map(|(start, end)| chars[start..end].iter().collect()).collect()
+        })
+    }
+
+    #[test]
+    fn flags_emails() {
+        assert_eq!(matches("reach me at alice@example.com, thanks", "emails"), ["alice@example.com"]);
+        assert!(matches("nothing to see here", "emails").is_empty());
+        with_py(|py| {
+            let flags = contains_pii(py, "nothing to see here").unwrap();
+            assert!(!flags.get_item("has_email").unwrap().unwrap().is_truthy().unwrap());
+            assert!(!flags.get_item("has_phone").unwrap().unwrap().is_truthy().unwrap());
+        });
+    }
+
+    #[test]
+    fn flags_common_phone_formats() {
+        for phone in ["555-123-4567", "555.123.4567", "(555) 123-4567", "+1 555 123 4567", "+44 20 7946 0958"] {
+            assert_eq!(matches(&format!("call {} today", phone), "phones"), [phone]);
+        }
+        assert!(matches("room 12-34", "phones").is_empty());
+        assert!(matches("alice5551234567@example.com", "phones").is_empty());
+    }
+
+    #[test]
+    fn separates_adjacent_phone_numbers() {
+        assert_eq!(matches("call 555-123-4567 555-987-6543", "phones"), ["555-123-4567", "555-987-6543"]);
+        assert_eq!(matches("555-123-4567\n555-987-6543", "phones"), ["555-123-4567", "555-987-6543"]);
+        assert_eq!(matches("+1 555 123 4567 555-987-6543", "phones"), ["+1 555 123 4567", "555-987-6543"]);
+    }
+
+    #[test]
+    fn spans_are_character_offsets() {
+        assert_eq!(matches("José: 555-123-4567", "phones"), ["555-123-4567"]);
+    }
+}
@@ -0,0 +1,80 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::user::User;
+
+/// Counts users per key, where the key is computed by a Python callable.
+///
+/// A flexible group-by: `key_fn` is called once per user, in order, and may return any
+/// hashable value. Keys appear in the result in the order they are first produced.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+/// * `key_fn` - A callable taking a User and returning a hashable key
+///
+/// # Returns
+///
+/// A dictionary mapping each key to the number of users that produced it
+///
+/// # Errors
+///
+/// Propagates any exception raised by `key_fn`, and the `TypeError` raised for an
+/// unhashable key.
+///
+/// # Examples
+///
+/// ```ignore
+/// count_by(users, lambda u: u.age // 10)  # {3: 2, 2: 1}
+/// ```
+#[pyfunction]
+pub fn count_by<'py>(py: Python<'py>, users: Vec<Bound<'py, User>>, key_fn: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    let counts = PyDict::new(py);
+    for user in users {
+        let key = key_fn.call1((user,))?;
+        let count = match counts.get_item(&key)? {
+            Some(count) => count.extract::<usize>()?,
+            None => 0,
+        };
+        counts.set_item(key, count + 1)?;
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, bind, message, user, with_py};
+
+    fn users(py: Python<'_>) -> Vec<Bound<'_, User>> {
+        bind(
+            py,
+            vec![
+                user(1, "Alice", "alice@example.com", 34, true),
+                user(2, "Bob", "bob@example.com", 25, false),
+                user(3, "Carol", "carol@example.com", 30, true),
+            ],
+        )
+    }
+
+    #[test]
+    fn counts_by_age_decade() {
+        with_py(|py| {
+            let key_fn = py.eval(c"lambda u: u.age // 10", None, None).unwrap();
+            let counts = count_by(py, users(py), key_fn).unwrap();
+            assert_py_eq(&counts, c"{3: 2, 2: 1}");
+            assert_eq!(counts.keys().iter().map(|k| k.extract::<i32>().unwrap()).collect::<Vec<_>>(), [3, 2]);
+        });
+    }
+
+    #[test]
+    fn key_errors_propagate() {
+        with_py(|py| {
+            let raising = py.eval(c"lambda u: 1 / 0", None, None).unwrap();
+            assert_eq!(message(count_by(py, users(py), raising).unwrap_err()), "division by zero");
+            let unhashable = py.eval(c"lambda u: [u.age]", None, None).unwrap();
+            assert!(count_by(py, users(py), unhashable).is_err());
+        });
+    }
+}
@@ -0,0 +1,70 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::user::User;
+
+/// Collapses users sharing the same `id` into a single record.
+///
+/// The output keeps the order in which each id first appears. When `prefer_latest` is
+/// false the first occurrence of an id wins; when true, the last occurrence's fields
+/// replace it (still at the position of the first appearance).
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects, possibly containing duplicate ids
+/// * `prefer_latest` - Keep the last occurrence of each id instead of the first
+///
+/// # Examples
+///
+/// ```ignore
+/// users = [User(1, "Alice", "a@x.com", 30, True), User(2, "Bob", "b@x.com", 25, True), User(1, "Alicia", "a@x.com", 31, True)]
+/// [u.name for u in dedup_by_id(users, True)]  # ["Alicia", "Bob"]
+/// ```
+#[pyfunction]
+pub fn dedup_by_id(users: Vec<User>, prefer_latest: bool) -> Vec<User> {
+    let mut positions: HashMap<i32, usize> = HashMap::with_capacity(users.len());
+    let mut deduped: Vec<User> = Vec::with_capacity(users.len());
+
+    for user in users {
+        match positions.get(&user.id) {
+            Some(&idx) => {
+                if prefer_latest {
+                    deduped[idx] = user;
+                }
+            }
+            None => {
+                positions.insert(user.id, deduped.len());
+                deduped.push(user);
+            }
+        }
+    }
+    deduped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::user;
+
+    fn users() -> Vec<User> {
+        vec![
+            user(1, "Alice", "a@x.com", 30, true),
+            user(2, "Bob", "b@x.com", 25, true),
+            user(1, "Alicia", "a@x.com", 31, true),
+        ]
+    }
+
+    #[test]
+    fn keeps_first_occurrence_by_default() {
+        let deduped = dedup_by_id(users(), false);
+        let names: Vec<&str> = deduped.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, ["Alice", "Bob"]);
+    }
+
+    #[test]
+    fn prefer_latest_replaces_fields_at_first_position() {
+        let deduped = dedup_by_id(users(), true);
+        let summary: Vec<(i32, &str, i32)> = deduped.iter().map(|u| (u.id, u.name.as_str(), u.age)).collect();
+        assert_eq!(summary, [(1, "Alicia", 31), (2, "Bob", 25)]);
+    }
+}
@@ -0,0 +1,106 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::{HashMap, HashSet};
+
+use crate::user::User;
+
+/// Records `field: (old, new)` in `changes` when the two values differ.
+fn record_change<'py, T>(changes: &Bound<'py, PyDict>, field: &str, old: T, new: T) -> PyResult<()>
+where
+    T: PartialEq + IntoPyObject<'py>,
+{
+    if old != new {
+        changes.set_item(field, (old, new))?;
+    }
+    Ok(())
+}
+
+/// Builds a dictionary of the non-id fields that differ between `old` and `new`.
+fn field_changes<'py>(py: Python<'py>, old: &User, new: &User) -> PyResult<Bound<'py, PyDict>> {
+    let changes = PyDict::new(py);
+    record_change(&changes, "name", &old.name, &new.name)?;
+    record_change(&changes, "email", &old.email, &new.email)?;
+    record_change(&changes, "age", old.age, new.age)?;
+    record_change(&changes, "status", old.status.as_str(), new.status.as_str())?;
+    record_change(&changes, "birthdate", &old.birthdate, &new.birthdate)?;
+    record_change(&changes, "weight", old.weight, new.weight)?;
+    record_change(&changes, "tags", &old.tags, &new.tags)?;
+    Ok(changes)
+}
+
+/// Compares two user lists by id and reports what was added, removed, and changed.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `old` - The previous list of users
+/// * `new` - The current list of users
+///
+/// # Returns
+///
+/// A dictionary with:
+/// - `"added"`: users in `new` whose id is not in `old`, in `new` order
+/// - `"removed"`: users in `old` whose id is not in `new`, in `old` order
+/// - `"changed"`: a dict mapping each id present in both lists with differing fields to
+///   `{field: (old_value, new_value)}`
+///
+/// # Examples
+///
+/// ```ignore
+/// diff = diff_user_lists(old_users, new_users)
+/// diff["changed"]  # {2: {"age": (25, 26)}}
+/// ```
+#[pyfunction]
+pub fn diff_user_lists(py: Python<'_>, old: Vec<User>, new: Vec<User>) -> PyResult<Bound<'_, PyDict>> {
+    let old_by_id: HashMap<i32, &User> = old.iter().map(|u| (u.id, u)).collect();
+    let new_ids: HashSet<i32> = new.iter().map(|u| u.id).collect();
+
+    let mut added: Vec<User> = Vec::new();
+    let changed = PyDict::new(py);
+    for user in &new {
+        match old_by_id.get(&user.id) {
+            None => added.push(user.clone()),
+            Some(previous) => {
+                let changes = field_changes(py, previous, user)?;
+                if !changes.is_empty() {
+                    changed.set_item(user.id, changes)?;
+                }
+            }
+        }
+    }
+    let removed: Vec<User> = old.iter().filter(|u| !new_ids.contains(&u.id)).cloned().collect();
+
+    let dict = PyDict::new(py);
+    dict.set_item("added", added)?;
+    dict.set_item("removed", removed)?;
+    dict.set_item("changed", changed)?;
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, user, with_py};
+
+    #[test]
+    fn reports_added_removed_and_changed_users() {
+        with_py(|py| {
+            let old = vec![
+                user(1, "Alice", "alice@example.com", 30, true),
+                user(2, "Bob", "bob@example.com", 25, true),
+            ];
+            let new = vec![
+                user(2, "Bob", "bob@example.com", 26, true),
+                user(3, "Carol", "carol@example.com", 41, false),
+            ];
+            let diff = diff_user_lists(py, old, new).unwrap();
+            let ids = |key: &str| -> Vec<i32> {
+                let users: Vec<User> = diff.get_item(key).unwrap().unwrap().extract().unwrap();
+                users.iter().map(|u| u.id).collect()
+            };
+            assert_eq!(ids("added"), [3]);
+            assert_eq!(ids("removed"), [1]);
+            assert_py_eq(&diff.get_item("changed").unwrap().unwrap(), c"{2: {'age': (25, 26)}}");
+        });
+    }
+}
@@ -0,0 +1,34 @@
+use pyo3::prelude::*;
+use std::collections::BTreeSet;
+
+use crate::user::User;
+
+/// Returns the unique ages present in a list of users, in ascending order.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Examples
+///
+/// ```ignore
+/// distinct_ages(users)  # [25, 30, 41]
+/// ```
+#[pyfunction]
+pub fn distinct_ages(users: Vec<PyRef<User>>) -> Vec<i32> {
+    users.iter().map(|u| u.age).collect::<BTreeSet<i32>>().into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+
+    #[test]
+    fn returns_unique_ages_in_order() {
+        with_py(|py| {
+            let users = bind(py, [41, 30, 41, 18, 30].into_iter().map(|age| user(1, "U", "u@x.com", age, true)).collect());
+            assert_eq!(distinct_ages(borrow_all(&users)), [18, 30, 41]);
+        });
+    }
+}
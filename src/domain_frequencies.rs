@@ -0,0 +1,58 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::BTreeMap;
+
+use crate::user::User;
+
+/// Counts how many users have an email at each domain.
+///
+/// Domains are lowercased before counting, so `Example.com` and `example.COM` are grouped
+/// together. Emails without an `@` are skipped.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A dictionary mapping each lowercased domain to its count, with keys in sorted order
+///
+/// # Examples
+///
+/// ```ignore
+/// domain_frequencies(users)  # {"example.com": 2, "test.org": 1}
+/// ```
+#[pyfunction]
+pub fn domain_frequencies<'py>(py: Python<'py>, users: Vec<PyRef<'py, User>>) -> PyResult<Bound<'py, PyDict>> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for domain in users.iter().filter_map(|user| user.email_domain()) {
+        *counts.entry(domain).or_default() += 1;
+    }
+    let dict = PyDict::new(py);
+    for (domain, count) in counts {
+        dict.set_item(domain, count)?;
+    }
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, bind, borrow_all, user, with_py};
+
+    #[test]
+    fn groups_domains_case_insensitively() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                ["a@Example.com", "b@example.COM", "c@test.org", "no-at-sign"]
+                    .into_iter()
+                    .map(|email| user(1, "U", email, 30, true))
+                    .collect(),
+            );
+            let counts = domain_frequencies(py, borrow_all(&users)).unwrap();
+            assert_py_eq(&counts, c"{'example.com': 2, 'test.org': 1}");
+        });
+    }
+}
@@ -0,0 +1,95 @@
+use pyo3::prelude::*;
+
+/// An exponential moving average updated one sample at a time
+#[pyclass(skip_from_py_object)]
+#[derive(Clone)]
+pub struct Ema {
+    #[pyo3(get)]
+    pub alpha: f64,
+    #[pyo3(get)]
+    pub value: Option<f64>,
+}
+
+#[pymethods]
+impl Ema {
+    /// Creates a new Ema with smoothing factor `alpha`; larger values weight recent samples more.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `alpha` is not in the interval (0, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// ema = Ema(0.5)
+    /// ema.value  # None
+    /// ```
+    #[new]
+    fn new(alpha: f64) -> PyResult<Self> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "alpha must be in (0, 1], got {}",
+                alpha
+            )));
+        }
+        Ok(Ema { alpha, value: None })
+    }
+
+    /// Folds `x` into the average and returns the updated value.
+    ///
+    /// The first sample initializes the average; after that each update computes
+    /// `alpha * x + (1 - alpha) * previous`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// ema = Ema(0.5)
+    /// ema.push(10.0)  # 10.0
+    /// ema.push(20.0)  # 15.0
+    /// ```
+    fn push(&mut self, x: f64) -> f64 {
+        let updated = match self.value {
+            Some(previous) => self.alpha * x + (1.0 - self.alpha) * previous,
+            None => x,
+        };
+        self.value = Some(updated);
+        updated
+    }
+
+    /// Formats a string representation of the average including its smoothing factor and value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// repr(Ema(0.5))  # "Ema(alpha=0.5, value=None)"
+    /// ```
+    fn __repr__(&self) -> String {
+        match self.value {
+            Some(value) => format!("Ema(alpha={}, value={})", self.alpha, value),
+            None => format!("Ema(alpha={}, value=None)", self.alpha),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::message;
+
+    #[test]
+    fn smooths_a_known_sequence() {
+        let mut ema = Ema::new(0.5).unwrap();
+        assert_eq!(ema.value, None);
+        let pushed: Vec<f64> = [10.0, 20.0, 20.0, 0.0].into_iter().map(|x| ema.push(x)).collect();
+        assert_eq!(pushed, [10.0, 15.0, 17.5, 8.75]);
+        assert_eq!(ema.value, Some(8.75));
+    }
+
+    #[test]
+    fn alpha_must_be_in_the_unit_interval() {
+        assert!(Ema::new(1.0).is_ok());
+        assert_eq!(message(Ema::new(0.0).err().unwrap()), "alpha must be in (0, 1], got 0");
+        assert!(Ema::new(1.5).is_err());
+        assert!(Ema::new(f64::NAN).is_err());
+    }
+}
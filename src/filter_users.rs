@@ -0,0 +1,65 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Keeps the users for which a Python predicate returns a truthy value.
+///
+/// The loop runs in Rust while the test itself stays in Python, so callers can filter on
+/// arbitrary logic. The predicate receives a copy of each user.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+/// * `predicate` - A callable taking a User and returning a value tested for truthiness
+///
+/// # Errors
+///
+/// Propagates any exception raised by `predicate` or by its result's `__bool__`.
+///
+/// # Examples
+///
+/// ```ignore
+/// filter_users(users, lambda u: u.age > 25)
+/// ```
+#[pyfunction]
+pub fn filter_users(users: Vec<User>, predicate: Bound<'_, PyAny>) -> PyResult<Vec<User>> {
+    let mut kept = Vec::new();
+    for user in users {
+        if predicate.call1((user.clone(),))?.is_truthy()? {
+            kept.push(user);
+        }
+    }
+    Ok(kept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{user, with_py};
+
+    fn users() -> Vec<User> {
+        vec![
+            user(1, "Alice", "alice@example.com", 30, true),
+            user(2, "Bob", "bob@example.com", 25, true),
+            user(3, "Carol", "carol@example.com", 41, false),
+        ]
+    }
+
+    #[test]
+    fn keeps_users_matching_the_predicate() {
+        with_py(|py| {
+            let predicate = py.eval(c"lambda u: u.age > 25", None, None).unwrap();
+            let kept = filter_users(users(), predicate).unwrap();
+            assert_eq!(kept.iter().map(|u| u.id).collect::<Vec<_>>(), [1, 3]);
+        });
+    }
+
+    #[test]
+    fn propagates_predicate_errors() {
+        with_py(|py| {
+            let predicate = py.eval(c"lambda u: u.age / 0", None, None).unwrap();
+            let err = filter_users(users(), predicate).err().unwrap();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyZeroDivisionError>(py));
+        });
+    }
+}
@@ -0,0 +1,64 @@
+use pyo3::prelude::*;
+
+/// A serialization format supported by `User.serialize`
+#[pyclass(eq, eq_int, from_py_object)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    Json,
+    JsonPretty,
+    Yaml,
+    Msgpack,
+    Toml,
+}
+
+impl Format {
+    /// Returns the snake_case name accepted by `parse`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::JsonPretty => "json_pretty",
+            Format::Yaml => "yaml",
+            Format::Msgpack => "msgpack",
+            Format::Toml => "toml",
+        }
+    }
+
+    /// Parses the snake_case name produced by `as_str`.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(Format::Json),
+            "json_pretty" => Some(Format::JsonPretty),
+            "yaml" => Some(Format::Yaml),
+            "msgpack" => Some(Format::Msgpack),
+            "toml" => Some(Format::Toml),
+            _ => None,
+        }
+    }
+}
+
+#[pymethods]
+impl Format {
+    /// Returns the snake_case name of the format.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// str(Format.JsonPretty)  # "json_pretty"
+    /// ```
+    fn __str__(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_round_trips_as_str() {
+        for format in [Format::Json, Format::JsonPretty, Format::Yaml, Format::Msgpack, Format::Toml] {
+            assert_eq!(Format::parse(format.as_str()), Some(format));
+        }
+        assert_eq!(Format::parse("xml"), None);
+    }
+}
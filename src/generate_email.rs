@@ -0,0 +1,70 @@
+use pyo3::prelude::*;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::validate_email::validate_email;
+
+/// Reduces one word of a name to lowercase ASCII letters and digits.
+///
+/// Accented letters are decomposed (NFD) so that dropping everything outside ASCII removes their
+/// combining marks, turning `é` into `e`; punctuation such as `'` or `-` is removed as well.
+fn email_slug(word: &str) -> String {
+    word.nfd()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Generates a normalized `first.last@domain` address from a display name, for seeding test data.
+///
+/// The first and last words of `name` are each reduced to lowercase ASCII alphanumerics, with
+/// accents stripped; middle words are dropped, and a single-word name yields `first@domain`.
+/// The domain is trimmed and lowercased.
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `name` has no letters or digits left after normalization, if
+/// `domain` is empty, or if the resulting address is rejected by `validate_email`.
+///
+/// # Examples
+///
+/// ```ignore
+/// generate_email("José García", "example.com")  # "jose.garcia@example.com"
+/// generate_email("Mary Ann O'Neil", "Example.COM")  # "mary.oneil@example.com"
+/// ```
+#[pyfunction]
+pub fn generate_email(name: &str, domain: &str) -> PyResult<String> {
+    let domain = domain.trim().to_lowercase();
+    if domain.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err("domain must not be empty"));
+    }
+    let words: Vec<String> = name.split_whitespace().map(email_slug).filter(|w| !w.is_empty()).collect();
+    let local = match words.as_slice() {
+        [] => return Err(pyo3::exceptions::PyValueError::new_err(format!("name '{}' has no usable characters", name))),
+        [only] => only.clone(),
+        [first, .., last] => format!("{}.{}", first, last),
+    };
+    let email = format!("{}@{}", local, domain);
+    if !validate_email(&email) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!("generated email '{}' is invalid", email)));
+    }
+    Ok(email)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::message;
+
+    #[test]
+    fn strips_accents_and_lowercases() {
+        assert_eq!(generate_email("José García", "example.com").unwrap(), "jose.garcia@example.com");
+        assert_eq!(generate_email("Mary Ann O'Neil", " Example.COM ").unwrap(), "mary.oneil@example.com");
+        assert_eq!(generate_email("Cher", "example.com").unwrap(), "cher@example.com");
+    }
+
+    #[test]
+    fn rejects_empty_name_or_domain() {
+        assert_eq!(message(generate_email("José García", "  ").unwrap_err()), "domain must not be empty");
+        assert_eq!(message(generate_email("!!", "example.com").unwrap_err()), "name '!!' has no usable characters");
+    }
+}
@@ -0,0 +1,46 @@
+use pyo3::prelude::*;
+
+/// Generates a formal greeting from a first name, last name, and optional title.
+///
+/// Each part is trimmed and empty parts are left out, so the returned string is
+/// "Good day, {title} {first} {last}." with only the parts that are present, or
+/// "Good day." if none are.
+///
+/// # Examples
+///
+/// ```ignore
+/// greet_formal("Jane", "Smith", "Dr.")  # "Good day, Dr. Jane Smith."
+/// greet_formal("Jane", "Smith", None)   # "Good day, Jane Smith."
+/// greet_formal(" Jane ", "", None)      # "Good day, Jane."
+/// ```
+#[pyfunction]
+#[pyo3(signature = (first, last, title=None))]
+pub fn greet_formal(first: &str, last: &str, title: Option<&str>) -> String {
+    let parts: Vec<&str> = [title.unwrap_or(""), first, last]
+        .iter()
+        .map(|part| part.trim())
+        .filter(|part| !part.is_empty())
+        .collect();
+    if parts.is_empty() {
+        "Good day.".to_string()
+    } else {
+        format!("Good day, {}.", parts.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_with_and_without_a_title() {
+        assert_eq!(greet_formal("Jane", "Smith", Some("Dr.")), "Good day, Dr. Jane Smith.");
+        assert_eq!(greet_formal("Jane", "Smith", None), "Good day, Jane Smith.");
+    }
+
+    #[test]
+    fn skips_empty_parts() {
+        assert_eq!(greet_formal(" Jane ", "", Some("  ")), "Good day, Jane.");
+        assert_eq!(greet_formal("", " ", None), "Good day.");
+    }
+}
@@ -0,0 +1,46 @@
+use pyo3::prelude::*;
+
+/// Uppercases the first letter of each whitespace-separated word, leaving other characters as-is.
+fn capitalize_words(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut at_word_start = true;
+    for c in name.chars() {
+        if at_word_start {
+            out.extend(c.to_uppercase());
+        } else {
+            out.push(c);
+        }
+        at_word_start = c.is_whitespace();
+    }
+    out
+}
+
+/// Generates a greeting for the given name with configurable punctuation and capitalization.
+///
+/// The returned string is formatted as "Hello, {name}!" when `exclaim` is true and
+/// "Hello, {name}." otherwise. When `title_case` is true, the first letter of each word
+/// in the name is capitalized.
+///
+/// # Examples
+///
+/// ```ignore
+/// greet_styled("john doe", False, True)  # "Hello, John Doe."
+/// greet_styled("john doe", True, False)  # "Hello, john doe!"
+/// ```
+#[pyfunction]
+pub fn greet_styled(name: &str, exclaim: bool, title_case: bool) -> String {
+    let name = if title_case { capitalize_words(name) } else { name.to_string() };
+    let punctuation = if exclaim { '!' } else { '.' };
+    format!("Hello, {}{}", name, punctuation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn title_cases_and_punctuates() {
+        assert_eq!(greet_styled("john doe", false, true), "Hello, John Doe.");
+        assert_eq!(greet_styled("john doe", true, false), "Hello, john doe!");
+    }
+}
@@ -0,0 +1,115 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::user::User;
+
+/// Running count and age total for one group.
+#[derive(Default)]
+struct AgeGroup {
+    count: i64,
+    total_age: i64,
+}
+
+impl AgeGroup {
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("count", self.count)?;
+        let avg_age = (self.count > 0).then(|| self.total_age as f64 / self.count as f64);
+        dict.set_item("avg_age", avg_age)?;
+        Ok(dict)
+    }
+}
+
+/// Partitions users on `field` and returns the count and average age of each group.
+///
+/// Only `"active"` is supported for now; new fields slot in as extra match arms that
+/// produce their own group keys. Every possible key is present in the result, and groups
+/// with no users report `avg_age` as `None`.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+/// * `field` - The name of the field to partition on
+///
+/// # Returns
+///
+/// A dictionary mapping each group key to `{"count": int, "avg_age": float | None}`
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `field` is not a supported grouping field.
+///
+/// # Examples
+///
+/// ```ignore
+/// group_by_field(users, "active")
+/// # {True: {"count": 2, "avg_age": 27.5}, False: {"count": 1, "avg_age": 40.0}}
+/// ```
+#[pyfunction]
+pub fn group_by_field<'py>(py: Python<'py>, users: Vec<PyRef<'py, User>>, field: &str) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    match field {
+        "active" => {
+            let mut groups: [AgeGroup; 2] = Default::default();
+            for user in &users {
+                let group = &mut groups[user.is_active() as usize];
+                group.count += 1;
+                group.total_age += user.age as i64;
+            }
+            dict.set_item(true, groups[1].to_dict(py)?)?;
+            dict.set_item(false, groups[0].to_dict(py)?)?;
+        }
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unsupported group-by field '{}'",
+                field
+            )))
+        }
+    }
+    Ok(dict)
+}
+
+/// Groups users by their `active` flag; shorthand for `group_by_field(users, "active")`.
+///
+/// # Examples
+///
+/// ```ignore
+/// group_by_active(users)[True]["count"]
+/// ```
+#[pyfunction]
+pub fn group_by_active<'py>(py: Python<'py>, users: Vec<PyRef<'py, User>>) -> PyResult<Bound<'py, PyDict>> {
+    group_by_field(py, users, "active")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, bind, borrow_all, message, user, with_py};
+
+    #[test]
+    fn groups_a_mixed_list_by_active() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![
+                    user(1, "Alice", "a@x.com", 30, true),
+                    user(2, "Bob", "b@x.com", 25, true),
+                    user(3, "Cara", "c@x.com", 40, false),
+                ],
+            );
+            let groups = group_by_active(py, borrow_all(&users)).unwrap();
+            assert_py_eq(&groups, c"{True: {'count': 2, 'avg_age': 27.5}, False: {'count': 1, 'avg_age': 40.0}}");
+        });
+    }
+
+    #[test]
+    fn empty_groups_and_unknown_fields() {
+        with_py(|py| {
+            let groups = group_by_field(py, Vec::new(), "active").unwrap();
+            assert_py_eq(&groups, c"{True: {'count': 0, 'avg_age': None}, False: {'count': 0, 'avg_age': None}}");
+            let err = group_by_field(py, Vec::new(), "age").unwrap_err();
+            assert_eq!(message(err), "unsupported group-by field 'age'");
+        });
+    }
+}
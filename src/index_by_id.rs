@@ -0,0 +1,78 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+
+use crate::user::User;
+
+/// Builds a dict mapping each user's `id` to the user, for O(1) lookups.
+///
+/// The values are the same User objects that were passed in, not copies, so changes made
+/// through the dict are visible in the original list.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+///
+/// # Errors
+///
+/// Returns a `PyValueError` naming the first duplicated id and the positions of its first
+/// two occurrences, as `assert_unique_ids` does.
+///
+/// # Examples
+///
+/// ```ignore
+/// by_id = index_by_id(users)
+/// by_id[1].name  # "Alice"
+/// ```
+#[pyfunction]
+pub fn index_by_id<'py>(py: Python<'py>, users: Vec<Bound<'py, User>>) -> PyResult<Bound<'py, PyDict>> {
+    let mut seen: HashMap<i32, usize> = HashMap::with_capacity(users.len());
+    let dict = PyDict::new(py);
+    for (index, user) in users.iter().enumerate() {
+        let id = user.borrow().id;
+        if let Some(first) = seen.insert(id, index) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "duplicate user id {} at indices {} and {}",
+                id, first, index
+            )));
+        }
+        dict.set_item(id, user)?;
+    }
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, message, user, with_py};
+
+    #[test]
+    fn maps_ids_to_the_same_objects() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![user(1, "Alice", "alice@example.com", 30, true), user(2, "Bob", "bob@example.com", 25, false)],
+            );
+            let by_id = index_by_id(py, users.clone()).unwrap();
+            assert_eq!(by_id.len(), 2);
+            for user in &users {
+                let id = user.borrow().id;
+                assert!(by_id.get_item(id).unwrap().unwrap().is(user));
+            }
+            assert!(by_id.get_item(3).unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn duplicate_id_is_rejected() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![user(1, "Alice", "alice@example.com", 30, true), user(1, "Al", "al@example.com", 25, true)],
+            );
+            let err = index_by_id(py, users).err().unwrap();
+            assert_eq!(message(err), "duplicate user id 1 at indices 0 and 1");
+        });
+    }
+}
@@ -0,0 +1,86 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::user::User;
+
+/// Coerces and validates a batch of raw dicts in one pass, as an entry point for ingestion pipelines.
+///
+/// Each dict is converted as in `coerce_user` and then checked as in `User.validate`. Records
+/// that fail either step are reported rather than raised, so one bad record does not abort
+/// the batch.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `dicts` - Raw user dicts with the same keys as `User.dict()`
+///
+/// # Returns
+///
+/// A dictionary with:
+/// - `users`: the valid users, in input order
+/// - `error_count`: the number of rejected records
+/// - `errors`: a list of `(index, message)` tuples, one per rejected record
+///
+/// # Examples
+///
+/// ```ignore
+/// result = ingest([
+///     {"id": 1, "name": "Alice", "email": "alice@example.com", "age": 30, "active": True},
+///     {"id": 2, "name": "Bob", "email": "not-an-email", "age": 25, "active": True},
+/// ])
+/// result["error_count"]  # 1
+/// result["errors"]  # [(1, "invalid email 'not-an-email'")]
+/// ```
+#[pyfunction]
+pub fn ingest<'py>(py: Python<'py>, dicts: Vec<Bound<'py, PyDict>>) -> PyResult<Bound<'py, PyDict>> {
+    let mut users = Vec::with_capacity(dicts.len());
+    let mut errors = Vec::new();
+    for (index, dict) in dicts.iter().enumerate() {
+        match User::from_py_dict(dict) {
+            Ok(user) => match user.validation_error() {
+                Some(reason) => errors.push((index, reason)),
+                None => users.push(user),
+            },
+            Err(e) => errors.push((index, e.value(py).to_string())),
+        }
+    }
+
+    let result = PyDict::new(py);
+    result.set_item("users", users)?;
+    result.set_item("error_count", errors.len())?;
+    result.set_item("errors", errors)?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, with_py};
+
+    #[test]
+    fn reports_the_invalid_record_and_keeps_the_rest() {
+        with_py(|py| {
+            let dicts: Vec<Bound<'_, PyDict>> = py
+                .eval(
+                    c"[{'id': 1, 'name': 'Alice', 'email': 'a@x.com', 'age': 30, 'active': True}, \
+                       {'id': 2, 'name': 'Bob', 'email': 'not-an-email', 'age': 25, 'active': True}, \
+                       {'id': 3, 'name': 'Carol', 'email': 'c@x.com', 'age': 41, 'status': 'deleted'}, \
+                       {'id': 4, 'name': 'Dan', 'email': 'd@x.com', 'active': True}]",
+                    None,
+                    None,
+                )
+                .unwrap()
+                .extract()
+                .unwrap();
+            let result = ingest(py, dicts).unwrap();
+
+            let users: Vec<User> = result.get_item("users").unwrap().unwrap().extract().unwrap();
+            assert_eq!(users.iter().map(|u| u.id).collect::<Vec<_>>(), [1, 3]);
+            assert_eq!(result.get_item("error_count").unwrap().unwrap().extract::<usize>().unwrap(), 2);
+            assert_py_eq(
+                &result.get_item("errors").unwrap().unwrap(),
+                c"[(1, \"invalid email 'not-an-email'\"), (3, 'missing field `age`')]",
+            );
+        });
+    }
+}
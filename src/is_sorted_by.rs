@@ -0,0 +1,73 @@
+use pyo3::prelude::*;
+use std::cmp::Ordering;
+
+use crate::user::User;
+
+/// Checks whether users are already ordered by `field`, without sorting them.
+///
+/// Equal neighbours are allowed, so a list is sorted if no user compares before the one
+/// preceding it. Names compare by Unicode code point, like Python's `str` ordering.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+/// * `field` - One of `"id"`, `"name"`, or `"age"`
+/// * `descending` - Check for descending rather than ascending order
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `field` is not one of the supported fields.
+///
+/// # Examples
+///
+/// ```ignore
+/// is_sorted_by(users, "age", False)  # True if ages never decrease
+/// ```
+#[pyfunction]
+pub fn is_sorted_by(users: Vec<PyRef<User>>, field: &str, descending: bool) -> PyResult<bool> {
+    let compare: fn(&User, &User) -> Ordering = match field {
+        "id" => |a, b| a.id.cmp(&b.id),
+        "name" => |a, b| a.name.cmp(&b.name),
+        "age" => |a, b| a.age.cmp(&b.age),
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unsupported sort field '{}'",
+                field
+            )))
+        }
+    };
+    let out_of_order = if descending { Ordering::Greater } else { Ordering::Less };
+    Ok(users.windows(2).all(|pair| compare(&pair[1], &pair[0]) != out_of_order))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, message, user, with_py};
+
+    #[test]
+    fn checks_sorted_and_unsorted_lists() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![
+                    user(1, "Carol", "c@x.com", 41, true),
+                    user(2, "Alice", "a@x.com", 30, true),
+                    user(3, "Bob", "b@x.com", 30, true),
+                ],
+            );
+            let sorted = |field: &str, descending: bool| is_sorted_by(borrow_all(&users), field, descending).unwrap();
+            assert!(sorted("id", false));
+            assert!(!sorted("id", true));
+            assert!(sorted("age", true));
+            assert!(!sorted("age", false));
+            assert!(!sorted("name", false) && !sorted("name", true));
+        });
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!(is_sorted_by(Vec::new(), "id", false).unwrap());
+        assert_eq!(message(is_sorted_by(Vec::new(), "email", false).unwrap_err()), "unsupported sort field 'email'");
+    }
+}
@@ -1,12 +1,73 @@
+mod active_ratio_by_domain;
 mod add;
+mod age_category;
+mod age_summary;
+mod aggregate_users;
+mod aggregate_users_parallel;
+mod assert_unique_emails;
+mod assert_unique_ids;
+mod batch_checksum;
+mod benchmark_attr_access;
 mod benchmark_pydantic_process;
 mod benchmark_pyo3_process;
+mod benchmark_string_hash;
+mod caching_greeter;
 mod calculator;
+mod calculator_snapshot;
+mod coerce_user;
+mod contains_pii;
+mod count_by;
+mod dedup_by_id;
+mod diff_user_lists;
+mod distinct_ages;
+mod domain_frequencies;
+mod ema;
+mod filter_users;
+mod format;
+mod generate_email;
 mod greet;
+mod greet_formal;
+mod greet_styled;
+mod group_by_field;
+mod index_by_id;
+mod ingest;
+mod is_sorted_by;
+mod median_age;
+mod money;
 mod multiply;
+mod name_index;
+mod normalize_users;
+mod paginate_users;
+mod partition_active;
 mod process_pydantic_users;
 mod process_pyo3_users;
+mod pseudonymize;
+mod sample_users;
+mod self_check;
+mod similarity_matrix;
+mod stats;
+mod status;
+#[cfg(test)]
+mod test_support;
+mod time_callable;
+mod timing_overhead_ns;
+mod top_n_by_age;
+mod total_string_bytes;
 mod user;
+mod user_age_stats;
+mod user_collection;
+mod user_parser;
+mod user_set_ops;
+mod user_size_bytes;
+mod users_from_columns;
+mod users_gzip_json;
+mod users_jsonl;
+mod users_to_columnar;
+mod users_to_table;
+mod validate_email;
+mod validate_emails;
+mod weighted_average_age;
+mod windowed_active_counts;
 
 use pyo3::prelude::*;
 
@@ -32,7 +93,76 @@ fn py_rust_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<user::User>()?;
     m.add_function(wrap_pyfunction!(process_pydantic_users::process_pydantic_users, m)?)?;
     m.add_function(wrap_pyfunction!(process_pyo3_users::process_pyo3_users, m)?)?;
+    m.add_function(wrap_pyfunction!(process_pyo3_users::process_pyo3_users_with_ids, m)?)?;
+    m.add_function(wrap_pyfunction!(process_pyo3_users::process_pyo3_users_traced, m)?)?;
     m.add_function(wrap_pyfunction!(benchmark_pydantic_process::benchmark_pydantic_process, m)?)?;
     m.add_function(wrap_pyfunction!(benchmark_pyo3_process::benchmark_pyo3_process, m)?)?;
+    m.add_function(wrap_pyfunction!(dedup_by_id::dedup_by_id, m)?)?;
+    m.add_class::<money::Money>()?;
+    m.add_function(wrap_pyfunction!(validate_email::validate_email, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_emails::validate_emails, m)?)?;
+    m.add_function(wrap_pyfunction!(user_size_bytes::user_size_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(users_jsonl::users_to_jsonl, m)?)?;
+    m.add_function(wrap_pyfunction!(users_jsonl::users_from_jsonl, m)?)?;
+    m.add_function(wrap_pyfunction!(group_by_field::group_by_field, m)?)?;
+    m.add_function(wrap_pyfunction!(group_by_field::group_by_active, m)?)?;
+    m.add_class::<caching_greeter::CachingGreeter>()?;
+    m.add_function(wrap_pyfunction!(sample_users::sample_users, m)?)?;
+    m.add_function(wrap_pyfunction!(users_from_columns::users_from_columns, m)?)?;
+    m.add_function(wrap_pyfunction!(pseudonymize::pseudonymize, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_user_lists::diff_user_lists, m)?)?;
+    m.add_function(wrap_pyfunction!(greet_styled::greet_styled, m)?)?;
+    m.add_function(wrap_pyfunction!(top_n_by_age::top_n_by_age, m)?)?;
+    m.add_class::<status::Status>()?;
+    m.add_function(wrap_pyfunction!(aggregate_users::aggregate_users, m)?)?;
+    m.add_function(wrap_pyfunction!(aggregate_users_parallel::aggregate_users_parallel, m)?)?;
+    m.add_function(wrap_pyfunction!(users_to_table::users_to_table, m)?)?;
+    m.add_class::<ema::Ema>()?;
+    m.add_function(wrap_pyfunction!(coerce_user::coerce_user, m)?)?;
+    m.add_function(wrap_pyfunction!(distinct_ages::distinct_ages, m)?)?;
+    m.add_class::<user_collection::UserCollection>()?;
+    m.add_function(wrap_pyfunction!(greet_formal::greet_formal, m)?)?;
+    m.add_function(wrap_pyfunction!(weighted_average_age::weighted_average_age, m)?)?;
+    m.add_function(wrap_pyfunction!(paginate_users::paginate_users, m)?)?;
+    m.add_function(wrap_pyfunction!(normalize_users::normalize_users, m)?)?;
+    m.add_function(wrap_pyfunction!(similarity_matrix::similarity_matrix, m)?)?;
+    m.add_function(wrap_pyfunction!(users_gzip_json::users_to_gzip_json, m)?)?;
+    m.add_function(wrap_pyfunction!(users_gzip_json::users_from_gzip_json, m)?)?;
+    m.add_function(wrap_pyfunction!(filter_users::filter_users, m)?)?;
+    m.add_class::<user_parser::UserParser>()?;
+    m.add_function(wrap_pyfunction!(total_string_bytes::total_string_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(is_sorted_by::is_sorted_by, m)?)?;
+    m.add_function(wrap_pyfunction!(domain_frequencies::domain_frequencies, m)?)?;
+    m.add_function(wrap_pyfunction!(self_check::self_check, m)?)?;
+    m.add_function(wrap_pyfunction!(age_category::age_category, m)?)?;
+    m.add_function(wrap_pyfunction!(age_category::categorize_users, m)?)?;
+    m.add_function(wrap_pyfunction!(benchmark_attr_access::benchmark_attr_access, m)?)?;
+    m.add_function(wrap_pyfunction!(partition_active::partition_active, m)?)?;
+    m.add_function(wrap_pyfunction!(batch_checksum::batch_checksum, m)?)?;
+    m.add_function(wrap_pyfunction!(median_age::median_age, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_email::set_strict_email, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_email::strict_email_enabled, m)?)?;
+    m.add_function(wrap_pyfunction!(contains_pii::contains_pii, m)?)?;
+    m.add_function(wrap_pyfunction!(time_callable::time_callable, m)?)?;
+    m.add_class::<format::Format>()?;
+    m.add_function(wrap_pyfunction!(windowed_active_counts::windowed_active_counts, m)?)?;
+    m.add_function(wrap_pyfunction!(user_set_ops::users_intersection, m)?)?;
+    m.add_function(wrap_pyfunction!(user_set_ops::users_union, m)?)?;
+    m.add_function(wrap_pyfunction!(user_set_ops::users_difference, m)?)?;
+    m.add_function(wrap_pyfunction!(timing_overhead_ns::timing_overhead_ns, m)?)?;
+    m.add_function(wrap_pyfunction!(age_summary::age_summary, m)?)?;
+    m.add_function(wrap_pyfunction!(users_to_columnar::users_to_columnar, m)?)?;
+    m.add_function(wrap_pyfunction!(ingest::ingest, m)?)?;
+    m.add_function(wrap_pyfunction!(active_ratio_by_domain::active_ratio_by_domain, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_email::generate_email, m)?)?;
+    m.add_class::<calculator_snapshot::CalculatorSnapshot>()?;
+    m.add_function(wrap_pyfunction!(benchmark_string_hash::benchmark_string_hash, m)?)?;
+    m.add_function(wrap_pyfunction!(assert_unique_ids::assert_unique_ids, m)?)?;
+    m.add_class::<name_index::NameIndex>()?;
+    m.add_function(wrap_pyfunction!(index_by_id::index_by_id, m)?)?;
+    m.add_function(wrap_pyfunction!(assert_unique_emails::assert_unique_emails, m)?)?;
+    m.add_class::<stats::Stats>()?;
+    m.add_function(wrap_pyfunction!(user_age_stats::user_age_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(count_by::count_by, m)?)?;
     Ok(())
 }
@@ -0,0 +1,85 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Returns the median age of the users, releasing the GIL while it is computed.
+///
+/// Ages are extracted while holding the GIL; the median is then found with a selection
+/// algorithm (`select_nth_unstable`, expected linear time) rather than a full sort, with
+/// the GIL released. For an even count the two middle ages are averaged.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - Any iterable of PyO3 User objects
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `users` is empty.
+///
+/// # Examples
+///
+/// ```ignore
+/// median_age(users)  # ages [40, 20, 30, 25] -> 27.5
+/// ```
+#[pyfunction]
+pub fn median_age(py: Python<'_>, users: Bound<'_, PyAny>) -> PyResult<f64> {
+    let mut ages: Vec<i32> = Vec::new();
+    for user_obj in users.try_iter()? {
+        ages.push(user_obj?.extract::<PyRef<User>>()?.age);
+    }
+    if ages.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err("cannot take the median age of no users"));
+    }
+
+    Ok(py.detach(|| {
+        let len = ages.len();
+        let (lower, &mut upper, _) = ages.select_nth_unstable(len / 2);
+        if len % 2 == 1 {
+            upper as f64
+        } else {
+            // Everything in `lower` is <= `upper`, so the lower middle is its maximum.
+            let lower_middle = *lower.iter().max().expect("even, non-empty input has a lower half");
+            (lower_middle as f64 + upper as f64) / 2.0
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, user, with_py};
+    use pyo3::types::PyList;
+    use rand::rngs::StdRng;
+    use rand::{RngExt, SeedableRng};
+
+    fn naive_median(mut ages: Vec<i32>) -> f64 {
+        ages.sort();
+        let mid = ages.len() / 2;
+        if ages.len() % 2 == 1 {
+            ages[mid] as f64
+        } else {
+            (ages[mid - 1] as f64 + ages[mid] as f64) / 2.0
+        }
+    }
+
+    #[test]
+    fn matches_a_sort_based_median() {
+        with_py(|py| {
+            let mut rng = StdRng::seed_from_u64(7);
+            for len in 1..40 {
+                let ages: Vec<i32> = (0..len).map(|_| rng.random_range(0..100)).collect();
+                let users = bind(py, ages.iter().map(|&age| user(1, "U", "u@x.com", age, true)).collect());
+                let list = PyList::new(py, users).unwrap().into_any();
+                assert_eq!(median_age(py, list).unwrap(), naive_median(ages.clone()), "{:?}", ages);
+            }
+        });
+    }
+
+    #[test]
+    fn empty_input_raises() {
+        with_py(|py| {
+            assert!(median_age(py, PyList::empty(py).into_any()).is_err());
+        });
+    }
+}
@@ -0,0 +1,222 @@
+use pyo3::prelude::*;
+
+/// A monetary amount stored as an integer count of minor units (e.g. cents).
+///
+/// Integer storage keeps sums exact, so `0.10 + 0.20` is exactly `0.30`
+/// instead of the `0.30000000000000004` produced by floats.
+#[pyclass(skip_from_py_object)]
+#[derive(Clone)]
+pub struct Money {
+    #[pyo3(get)]
+    pub minor_units: i64,
+    #[pyo3(get)]
+    pub currency: String,
+}
+
+impl Money {
+    /// Ensures `other` uses the same currency as `self`.
+    fn check_currency(&self, other: &Money) -> PyResult<()> {
+        if self.currency != other.currency {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "currency mismatch: {} vs {}",
+                self.currency, other.currency
+            )));
+        }
+        Ok(())
+    }
+
+    fn with_minor_units(&self, minor_units: Option<i64>) -> PyResult<Money> {
+        let minor_units = minor_units.ok_or_else(|| pyo3::exceptions::PyOverflowError::new_err("money amount overflow"))?;
+        Ok(Money {
+            minor_units,
+            currency: self.currency.clone(),
+        })
+    }
+}
+
+#[pymethods]
+impl Money {
+    /// Creates a new Money value from a count of minor units and a currency code.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// price = Money(1999, "USD")
+    /// price.format()  # "19.99 USD"
+    /// ```
+    #[new]
+    #[pyo3(signature = (minor_units, currency="USD".to_string()))]
+    fn new(minor_units: i64, currency: String) -> Self {
+        Money { minor_units, currency }
+    }
+
+    /// Parses a decimal string such as `"0.10"` or `"-12.5"` into Money without going through floats.
+    ///
+    /// At most two fractional digits are accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `amount` is not a decimal number with up to two fractional digits,
+    /// or a `PyOverflowError` if it does not fit in an `i64` count of minor units.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Money.from_decimal("0.10", "USD").minor_units  # 10
+    /// ```
+    #[staticmethod]
+    #[pyo3(signature = (amount, currency="USD".to_string()))]
+    fn from_decimal(amount: &str, currency: String) -> PyResult<Self> {
+        let invalid = || pyo3::exceptions::PyValueError::new_err(format!("invalid decimal amount '{}'", amount));
+        let trimmed = amount.trim();
+        let (negative, digits) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed),
+        };
+        let (major, minor) = digits.split_once('.').unwrap_or((digits, ""));
+        if (major.is_empty() && minor.is_empty())
+            || minor.len() > 2
+            || !major.chars().chain(minor.chars()).all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let major: i64 = if major.is_empty() { 0 } else { major.parse().map_err(|_| invalid())? };
+        let minor: i64 = format!("{:0<2}", minor).parse().map_err(|_| invalid())?;
+        let minor_units = major
+            .checked_mul(100)
+            .and_then(|m| m.checked_add(minor))
+            .ok_or_else(|| pyo3::exceptions::PyOverflowError::new_err("money amount overflow"))?;
+
+        Ok(Money {
+            minor_units: if negative { -minor_units } else { minor_units },
+            currency,
+        })
+    }
+
+    /// Returns the sum of `self` and `other` as a new Money value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the currencies differ, or a `PyOverflowError` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Money(10, "USD").add(Money(20, "USD")).format()  # "0.30 USD"
+    /// ```
+    fn add(&self, other: &Money) -> PyResult<Money> {
+        self.check_currency(other)?;
+        self.with_minor_units(self.minor_units.checked_add(other.minor_units))
+    }
+
+    /// Returns `self` minus `other` as a new Money value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the currencies differ, or a `PyOverflowError` on overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Money(100, "USD").subtract(Money(1, "USD")).format()  # "0.99 USD"
+    /// ```
+    fn subtract(&self, other: &Money) -> PyResult<Money> {
+        self.check_currency(other)?;
+        self.with_minor_units(self.minor_units.checked_sub(other.minor_units))
+    }
+
+    /// Multiplies the amount by `factor`, rounding half away from zero to the nearest minor unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `factor` is not finite, or a `PyOverflowError` if the result
+    /// does not fit in an `i64` count of minor units.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Money(999, "USD").multiply_scalar(1.5).format()  # "14.99 USD"
+    /// ```
+    fn multiply_scalar(&self, factor: f64) -> PyResult<Money> {
+        if !factor.is_finite() {
+            return Err(pyo3::exceptions::PyValueError::new_err("factor must be finite"));
+        }
+        let product = (self.minor_units as f64 * factor).round();
+        let minor_units = if product >= i64::MIN as f64 && product < i64::MAX as f64 {
+            Some(product as i64)
+        } else {
+            None
+        };
+        self.with_minor_units(minor_units)
+    }
+
+    /// Formats the amount with two decimal places followed by the currency code.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// Money(-5, "EUR").format()  # "-0.05 EUR"
+    /// ```
+    fn format(&self) -> String {
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let abs = self.minor_units.unsigned_abs();
+        format!("{}{}.{:02} {}", sign, abs / 100, abs % 100, self.currency)
+    }
+
+    /// Formats a string representation of the money value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// repr(Money(30, "USD"))  # "Money(minor_units=30, currency='USD')"
+    /// ```
+    fn __repr__(&self) -> String {
+        format!("Money(minor_units={}, currency='{}')", self.minor_units, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::message;
+
+    #[test]
+    fn sums_stay_exact_over_a_long_sequence() {
+        let dime = Money::from_decimal("0.10", "USD".to_string()).unwrap();
+        let mut total = Money::new(0, "USD".to_string());
+        for _ in 0..10_000 {
+            total = total.add(&dime).unwrap();
+        }
+        assert_eq!(total.minor_units, 100_000);
+        assert_eq!(total.format(), "1000.00 USD");
+
+        let mut float_total = 0.0;
+        for _ in 0..10_000 {
+            float_total += 0.10;
+        }
+        assert_ne!(float_total, 1000.0);
+    }
+
+    #[test]
+    fn from_decimal_parses_without_floats() {
+        let parse = |s: &str| Money::from_decimal(s, "USD".to_string()).map(|m| m.minor_units);
+        assert_eq!(parse("0.10").unwrap(), 10);
+        assert_eq!(parse("-12.5").unwrap(), -1250);
+        assert_eq!(parse(".07").unwrap(), 7);
+        assert!(parse("1.234").is_err());
+        assert!(parse("abc").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn arithmetic_checks_currency_and_rounds_scalars() {
+        let usd = Money::new(100, "USD".to_string());
+        let eur = Money::new(100, "EUR".to_string());
+        assert_eq!(message(usd.add(&eur).err().unwrap()), "currency mismatch: USD vs EUR");
+        assert_eq!(usd.subtract(&Money::new(1, "USD".to_string())).unwrap().format(), "0.99 USD");
+        assert_eq!(Money::new(999, "USD".to_string()).multiply_scalar(1.5).unwrap().minor_units, 1499);
+        assert_eq!(Money::new(-5, "EUR".to_string()).format(), "-0.05 EUR");
+        assert!(Money::new(i64::MAX, "USD".to_string()).add(&usd).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// A prefix index over user names for autocomplete.
+///
+/// Users are kept sorted by lowercased name, so every match for a prefix lies in one
+/// contiguous run that is found by binary search. The index holds copies of the users;
+/// later changes to the originals are not reflected.
+#[pyclass(skip_from_py_object)]
+pub struct NameIndex {
+    /// `(lowercased name, user)` pairs sorted by name; ties keep the input order.
+    entries: Vec<(String, User)>,
+}
+
+#[pymethods]
+impl NameIndex {
+    /// Builds an index over `users`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// index = NameIndex(users)
+    /// ```
+    #[new]
+    fn new(users: Vec<User>) -> Self {
+        let mut entries: Vec<(String, User)> = users.into_iter().map(|user| (user.name.to_lowercase(), user)).collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        NameIndex { entries }
+    }
+
+    /// Returns the users whose name starts with `prefix`, ignoring case, in name order.
+    ///
+    /// An empty prefix matches every user.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// index = NameIndex([User(1, "Alice", ...), User(2, "alan", ...), User(3, "Bob", ...)])
+    /// [u.name for u in index.with_prefix("AL")]  # ["alan", "Alice"]
+    /// ```
+    fn with_prefix(&self, prefix: &str) -> Vec<User> {
+        let prefix = prefix.to_lowercase();
+        let start = self.entries.partition_point(|(name, _)| name.as_str() < prefix.as_str());
+        self.entries[start..]
+            .iter()
+            .take_while(|(name, _)| name.starts_with(&prefix))
+            .map(|(_, user)| user.clone())
+            .collect()
+    }
+
+    /// Returns the number of indexed users.
+    fn __len__(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::user;
+
+    fn names(users: Vec<User>) -> Vec<String> {
+        users.into_iter().map(|user| user.name).collect()
+    }
+
+    #[test]
+    fn finds_shared_prefix_ignoring_case() {
+        let index = NameIndex::new(vec![
+            user(1, "Alice", "alice@example.com", 30, true),
+            user(2, "Bob", "bob@example.com", 25, true),
+            user(3, "alan", "alan@example.com", 40, true),
+            user(4, "Albert", "albert@example.com", 50, false),
+            user(5, "Al", "al@example.com", 20, true),
+        ]);
+        assert_eq!(index.__len__(), 5);
+        assert_eq!(names(index.with_prefix("AL")), ["Al", "alan", "Albert", "Alice"]);
+        assert_eq!(names(index.with_prefix("ali")), ["Alice"]);
+        assert_eq!(names(index.with_prefix("b")), ["Bob"]);
+        assert!(index.with_prefix("carol").is_empty());
+        assert_eq!(index.with_prefix("").len(), 5);
+    }
+}
@@ -0,0 +1,63 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Normalizes the name and email of each user in place and returns how many users changed.
+///
+/// Names are trimmed with internal whitespace collapsed to single spaces; emails are trimmed
+/// and lowercased. The users are mutated through their Python references, so the caller's
+/// objects are updated without copying them back and forth.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Errors
+///
+/// Returns a `PyRuntimeError` if a user is currently borrowed elsewhere.
+///
+/// # Examples
+///
+/// ```ignore
+/// users = [User(1, "  Alice   Smith ", " Alice@Example.COM", 30, True)]
+/// normalize_users(users)  # 1
+/// users[0].email  # "alice@example.com"
+/// ```
+#[pyfunction]
+pub fn normalize_users(py: Python<'_>, users: Vec<Py<User>>) -> PyResult<usize> {
+    let mut changed = 0;
+    for user in &users {
+        if user.try_borrow_mut(py)?.normalize() {
+            changed += 1;
+        }
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{user, with_py};
+
+    #[test]
+    fn normalizes_dirty_users_and_counts_changes() {
+        with_py(|py| {
+            let users: Vec<Py<User>> = [
+                user(1, "  Alice   Smith ", " Alice@Example.COM ", 30, true),
+                user(2, "Bob", "bob@example.com", 25, true),
+                user(3, "Carol", "CAROL@x.com", 41, false),
+            ]
+            .into_iter()
+            .map(|u| Py::new(py, u).unwrap())
+            .collect();
+            let handles: Vec<Py<User>> = users.iter().map(|u| u.clone_ref(py)).collect();
+
+            assert_eq!(normalize_users(py, users).unwrap(), 2);
+            let alice = handles[0].borrow(py);
+            assert_eq!((alice.name.as_str(), alice.email.as_str()), ("Alice Smith", "alice@example.com"));
+            drop(alice);
+            assert_eq!(handles[2].borrow(py).email, "carol@x.com");
+            assert_eq!(normalize_users(py, handles.iter().map(|u| u.clone_ref(py)).collect()).unwrap(), 0);
+        });
+    }
+}
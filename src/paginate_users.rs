@@ -0,0 +1,86 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::user::User;
+
+/// Splits a list of users into pages and returns one page along with pagination metadata.
+///
+/// Pages are numbered from 1. An empty list still has a single, empty first page.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+/// * `page` - The 1-based page number to return
+/// * `per_page` - The maximum number of users per page
+///
+/// # Returns
+///
+/// A dict with the keys `"users"` (the users on the requested page), `"total"` (the number of
+/// users overall), `"total_pages"`, and `"has_next"` (whether a later page exists).
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `per_page` is 0 or `page` is outside `1..=total_pages`.
+///
+/// # Examples
+///
+/// ```ignore
+/// result = paginate_users(users, 2, 2)  # with 5 users
+/// [u.id for u in result["users"]]  # [3, 4]
+/// result["has_next"]  # True
+/// ```
+#[pyfunction]
+pub fn paginate_users(py: Python<'_>, users: Vec<User>, page: usize, per_page: usize) -> PyResult<Bound<'_, PyDict>> {
+    if per_page == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("per_page must be greater than 0"));
+    }
+    let total = users.len();
+    let total_pages = total.div_ceil(per_page).max(1);
+    if page == 0 || page > total_pages {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "page {} is out of range 1..={}",
+            page, total_pages
+        )));
+    }
+
+    let page_users: Vec<User> = users.into_iter().skip((page - 1) * per_page).take(per_page).collect();
+    let dict = PyDict::new(py);
+    dict.set_item("users", page_users)?;
+    dict.set_item("total", total)?;
+    dict.set_item("total_pages", total_pages)?;
+    dict.set_item("has_next", page < total_pages)?;
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{message, user, with_py};
+
+    fn five_users() -> Vec<User> {
+        (1..=5).map(|id| user(id, "U", "u@x.com", 30, true)).collect()
+    }
+
+    #[test]
+    fn returns_the_second_page() {
+        with_py(|py| {
+            let page = paginate_users(py, five_users(), 2, 2).unwrap();
+            let users: Vec<User> = page.get_item("users").unwrap().unwrap().extract().unwrap();
+            assert_eq!(users.iter().map(|u| u.id).collect::<Vec<_>>(), [3, 4]);
+            let field = |key: &str| page.get_item(key).unwrap().unwrap();
+            assert_eq!(field("total").extract::<usize>().unwrap(), 5);
+            assert_eq!(field("total_pages").extract::<usize>().unwrap(), 3);
+            assert!(field("has_next").extract::<bool>().unwrap());
+        });
+    }
+
+    #[test]
+    fn rejects_out_of_range_pages() {
+        with_py(|py| {
+            assert_eq!(message(paginate_users(py, five_users(), 4, 2).unwrap_err()), "page 4 is out of range 1..=3");
+            assert!(paginate_users(py, five_users(), 0, 2).is_err());
+            assert!(paginate_users(py, five_users(), 1, 0).is_err());
+            assert!(paginate_users(py, Vec::new(), 1, 2).is_ok());
+        });
+    }
+}
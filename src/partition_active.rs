@@ -0,0 +1,43 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Splits users into active and inactive lists in a single pass.
+///
+/// Each list keeps the users in their original relative order. Suspended and deleted
+/// users both count as inactive.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A tuple `(active, inactive)`
+///
+/// # Examples
+///
+/// ```ignore
+/// active, inactive = partition_active(users)
+/// ```
+#[pyfunction]
+pub fn partition_active(users: Vec<User>) -> (Vec<User>, Vec<User>) {
+    users.into_iter().partition(User::is_active)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::user;
+
+    #[test]
+    fn splits_and_preserves_order() {
+        let users = [(1, true), (2, false), (3, true), (4, false), (5, true)]
+            .into_iter()
+            .map(|(id, active)| user(id, "U", "u@x.com", 30, active))
+            .collect();
+        let (active, inactive) = partition_active(users);
+        assert_eq!(active.iter().map(|u| u.id).collect::<Vec<_>>(), [1, 3, 5]);
+        assert_eq!(inactive.iter().map(|u| u.id).collect::<Vec<_>>(), [2, 4]);
+    }
+}
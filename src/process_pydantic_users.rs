@@ -1,6 +1,8 @@
 use pyo3::prelude::*;
 use std::time::Instant;
 
+use crate::benchmark_pydantic_process::try_get_active_age;
+
 /// Process a list of Pydantic User objects using getattr for attribute access.
 ///
 /// This function demonstrates the "Border Tax" - the overhead of accessing Python object
@@ -10,6 +12,8 @@ use std::time::Instant;
 ///
 /// * `py` - Python GIL token
 /// * `users` - A list of Python objects (expected to be Pydantic User instances)
+/// * `on_error` - `"fail"` (default) to raise on the first object missing `active` or `age`,
+///   or `"skip"` to count such objects and continue, as `benchmark_pydantic_process` does
 ///
 /// # Returns
 ///
@@ -17,32 +21,90 @@ use std::time::Instant;
 /// - The sum of ages for active users
 /// - The count of active users
 /// - The elapsed time in microseconds
+/// - The count of objects skipped because of attribute errors (always 0 in `"fail"` mode)
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `on_error` is not `"fail"` or `"skip"`.
 ///
 /// # Note
 ///
 /// Uses PyResult to handle potential AttributeError during getattr, which is part of the "tax"
 #[pyfunction]
-pub fn process_pydantic_users(_py: Python<'_>, users: Bound<'_, PyAny>) -> PyResult<(i64, i64, f64)> {
+#[pyo3(signature = (users, on_error="fail"))]
+pub fn process_pydantic_users(
+    _py: Python<'_>,
+    users: Bound<'_, PyAny>,
+    on_error: &str,
+) -> PyResult<(i64, i64, f64, i64)> {
+    let skip_errors = match on_error {
+        "fail" => false,
+        "skip" => true,
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "on_error must be 'fail' or 'skip', got '{}'",
+                on_error
+            )))
+        }
+    };
+
     let start = Instant::now();
 
     let mut total_age: i64 = 0;
     let mut active_count: i64 = 0;
+    let mut errors: i64 = 0;
 
     // Iterate through the Python list
     for user_obj in users.try_iter()? {
         let user_obj = user_obj?;
 
-
         // getattr involves string hash + dictionary lookup - this is the "Access Tax"
-        let active: bool = user_obj.getattr("active")?.extract()?;
-
-        if active {
-            let age: i32 = user_obj.getattr("age")?.extract()?;
-            total_age += age as i64;
-            active_count += 1;
+        match try_get_active_age(&user_obj) {
+            Ok(Some(age)) => {
+                total_age += age as i64;
+                active_count += 1;
+            }
+            Ok(None) => {}
+            Err(_) if skip_errors => errors += 1,
+            Err(e) => return Err(e),
         }
     }
 
     let elapsed = start.elapsed().as_micros() as f64;
-    Ok((total_age, active_count, elapsed))
+    Ok((total_age, active_count, elapsed, errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{message, with_py};
+
+    fn objects(py: Python<'_>) -> Bound<'_, PyAny> {
+        py.eval(
+            c"[__import__('types').SimpleNamespace(active=a, age=g) for a, g in [(True, 30), (False, 99), (True, 12)]] + [object()]",
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn skip_counts_bad_objects_and_continues() {
+        with_py(|py| {
+            let (total_age, active_count, _, errors) = process_pydantic_users(py, objects(py), "skip").unwrap();
+            assert_eq!((total_age, active_count, errors), (42, 2, 1));
+        });
+    }
+
+    #[test]
+    fn fail_raises_and_unknown_modes_are_rejected() {
+        with_py(|py| {
+            let err = process_pydantic_users(py, objects(py), "fail").unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyAttributeError>(py));
+            assert_eq!(
+                message(process_pydantic_users(py, objects(py), "ignore").unwrap_err()),
+                "on_error must be 'fail' or 'skip', got 'ignore'"
+            );
+        });
+    }
 }
@@ -1,4 +1,5 @@
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use std::time::Instant;
 
 use crate::user::User;
@@ -34,7 +35,7 @@ pub fn process_pyo3_users(_py: Python<'_>, users: Bound<'_, PyAny>) -> PyResult<
         let user = user_obj.extract::<PyRef<User>>()?;
 
         // Direct field access - no dictionary lookup, fixed memory offset
-        if user.active {
+        if user.is_active() {
             total_age += user.age as i64;
             active_count += 1;
         }
@@ -43,3 +44,133 @@ pub fn process_pyo3_users(_py: Python<'_>, users: Bound<'_, PyAny>) -> PyResult<
     let elapsed = start.elapsed().as_micros() as f64;
     Ok((total_age, active_count, elapsed))
 }
+
+/// Like `process_pyo3_users`, but also reports which users were counted.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A tuple containing:
+/// - The sum of ages for active users
+/// - The count of active users
+/// - The elapsed time in microseconds
+/// - The ids of the active users, in input order
+#[pyfunction]
+pub fn process_pyo3_users_with_ids(_py: Python<'_>, users: Bound<'_, PyAny>) -> PyResult<(i64, i64, f64, Vec<i32>)> {
+    let start = Instant::now();
+
+    let mut total_age: i64 = 0;
+    let mut active_count: i64 = 0;
+    let mut ids = Vec::new();
+
+    for user_obj in users.try_iter()? {
+        let user_obj = user_obj?;
+        let user = user_obj.extract::<PyRef<User>>()?;
+
+        if user.is_active() {
+            total_age += user.age as i64;
+            active_count += 1;
+            ids.push(user.id);
+        }
+    }
+
+    let elapsed = start.elapsed().as_micros() as f64;
+    Ok((total_age, active_count, elapsed, ids))
+}
+
+/// Like `process_pyo3_users`, but also counts the `User` extractions performed.
+///
+/// Each user is extracted once as a `PyRef<User>`: a type check plus a borrow of the existing
+/// object. No `User` is cloned, unlike APIs that take `Vec<User>` and copy every user (and its
+/// strings) on entry.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A dictionary with:
+/// - `total_age`: the sum of ages for active users
+/// - `active_count`: the count of active users
+/// - `elapsed_us`: the elapsed time in microseconds
+/// - `extractions`: the number of `PyRef<User>` extractions, equal to the list length
+///
+/// # Examples
+///
+/// ```ignore
+/// process_pyo3_users_traced(users)["extractions"] == len(users)  # True
+/// ```
+#[pyfunction]
+pub fn process_pyo3_users_traced<'py>(py: Python<'py>, users: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    let start = Instant::now();
+
+    let mut total_age: i64 = 0;
+    let mut active_count: i64 = 0;
+    let mut extractions: usize = 0;
+
+    for user_obj in users.try_iter()? {
+        let user_obj = user_obj?;
+        let user = user_obj.extract::<PyRef<User>>()?;
+        extractions += 1;
+
+        if user.is_active() {
+            total_age += user.age as i64;
+            active_count += 1;
+        }
+    }
+
+    let elapsed = start.elapsed().as_micros() as f64;
+    let dict = PyDict::new(py);
+    dict.set_item("total_age", total_age)?;
+    dict.set_item("active_count", active_count)?;
+    dict.set_item("elapsed_us", elapsed)?;
+    dict.set_item("extractions", extractions)?;
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, user, with_py};
+    use pyo3::types::PyList;
+
+    fn users(py: Python<'_>) -> Bound<'_, PyAny> {
+        let users = bind(
+            py,
+            vec![
+                user(1, "Alice", "alice@example.com", 30, true),
+                user(2, "Bob", "bob@example.com", 25, false),
+                user(3, "Carol", "carol@example.com", 41, true),
+            ],
+        );
+        PyList::new(py, users).unwrap().into_any()
+    }
+
+    #[test]
+    fn ids_match_the_active_users() {
+        with_py(|py| {
+            let (total_age, active_count, _, ids) = process_pyo3_users_with_ids(py, users(py)).unwrap();
+            assert_eq!((total_age, active_count, ids), (71, 2, vec![1, 3]));
+            let (total_age, active_count, _) = process_pyo3_users(py, users(py)).unwrap();
+            assert_eq!((total_age, active_count), (71, 2));
+        });
+    }
+
+    #[test]
+    fn traced_counts_one_extraction_per_user() {
+        with_py(|py| {
+            let stats = process_pyo3_users_traced(py, users(py)).unwrap();
+            let get = |key: &str| stats.get_item(key).unwrap().unwrap().extract::<i64>().unwrap();
+            assert_eq!(get("extractions"), 3);
+            assert_eq!((get("total_age"), get("active_count")), (71, 2));
+            let empty = process_pyo3_users_traced(py, PyList::empty(py).into_any()).unwrap();
+            assert_eq!(empty.get_item("extractions").unwrap().unwrap().extract::<usize>().unwrap(), 0);
+        });
+    }
+}
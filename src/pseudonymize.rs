@@ -0,0 +1,58 @@
+use pyo3::prelude::*;
+
+use crate::user::{sha256_hex, User};
+
+/// Number of hex digits of the digest kept in each pseudonym.
+const PSEUDONYM_HEX_LEN: usize = 12;
+
+/// Replaces each user's name with a deterministic pseudonym derived from its id and `salt`.
+///
+/// The pseudonym is `user_` followed by the first 12 hex digits of `SHA-256("{id}:{salt}")`,
+/// so a given user always maps to the same pseudonym for the same salt, across calls and
+/// runs, while different salts produce unrelated pseudonyms. All other fields are kept.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+/// * `salt` - Secret mixed into the hash so pseudonyms cannot be recomputed from ids alone
+///
+/// # Examples
+///
+/// ```ignore
+/// pseudonymize([User(1, "Alice", "alice@example.com", 30, True)], "s3cret")[0].name
+/// # "user_..." (stable for this id and salt)
+/// ```
+#[pyfunction]
+pub fn pseudonymize(users: Vec<User>, salt: &str) -> Vec<User> {
+    users
+        .into_iter()
+        .map(|mut user| {
+            let digest = sha256_hex(format!("{}:{}", user.id, salt).as_bytes());
+            user.name = format!("user_{}", &digest[..PSEUDONYM_HEX_LEN]);
+            user
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::user;
+
+    fn names(salt: &str) -> Vec<String> {
+        let users = vec![
+            user(1, "Alice", "alice@example.com", 30, true),
+            user(2, "Bob", "bob@example.com", 25, false),
+        ];
+        pseudonymize(users, salt).into_iter().map(|u| u.name).collect()
+    }
+
+    #[test]
+    fn pseudonyms_are_consistent_per_salt() {
+        let first = names("s3cret");
+        assert_eq!(first, names("s3cret"));
+        assert_ne!(first[0], first[1]);
+        assert!(first[0].starts_with("user_") && first[0].len() == 5 + PSEUDONYM_HEX_LEN);
+        assert_ne!(first, names("other"));
+    }
+}
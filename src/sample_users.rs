@@ -0,0 +1,85 @@
+use pyo3::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+
+use crate::user::User;
+
+/// Draws a uniform random sample of up to `k` users from an iterable using reservoir sampling.
+///
+/// The input is consumed lazily, so arbitrarily large streams (including generators) can be
+/// sampled while only `k` users are held in memory. Items that are never selected are not
+/// converted into `User` values. The same `seed` over the same input always yields the same sample.
+///
+/// # Arguments
+///
+/// * `users` - Any iterable of PyO3 User objects
+/// * `k` - The maximum sample size
+/// * `seed` - Seed for the random number generator
+///
+/// # Returns
+///
+/// A list of at most `k` users; if the input has fewer than `k` users, all of them are returned
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `k` is 0, or propagates errors from iteration and extraction.
+///
+/// # Examples
+///
+/// ```ignore
+/// sample = sample_users(iter(users), 10, seed=42)
+/// ```
+#[pyfunction]
+pub fn sample_users(users: Bound<'_, PyAny>, k: usize, seed: u64) -> PyResult<Vec<User>> {
+    if k == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("k must be greater than 0"));
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut reservoir: Vec<User> = Vec::new();
+
+    for (i, user_obj) in users.try_iter()?.enumerate() {
+        let user_obj = user_obj?;
+        if i < k {
+            reservoir.push(user_obj.extract::<User>()?);
+        } else {
+            let j = rng.random_range(0..=i);
+            if j < k {
+                reservoir[j] = user_obj.extract::<User>()?;
+            }
+        }
+    }
+    Ok(reservoir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, user, with_py};
+    use pyo3::types::PyList;
+
+    fn ids(sample: &[User]) -> Vec<i32> {
+        sample.iter().map(|u| u.id).collect()
+    }
+
+    #[test]
+    fn same_seed_yields_same_sample() {
+        with_py(|py| {
+            let users = bind(py, (0..50).map(|i| user(i, "User", "user@example.com", 30, true)).collect());
+            let list = PyList::new(py, users).unwrap().into_any();
+            let first = sample_users(list.clone(), 5, 42).unwrap();
+            let second = sample_users(list, 5, 42).unwrap();
+            assert_eq!(first.len(), 5);
+            assert_eq!(ids(&first), ids(&second));
+        });
+    }
+
+    #[test]
+    fn huge_k_returns_every_user() {
+        with_py(|py| {
+            let users = bind(py, (0..3).map(|i| user(i, "User", "user@example.com", 30, true)).collect());
+            let list = PyList::new(py, users).unwrap().into_any();
+            assert_eq!(ids(&sample_users(list, usize::MAX, 7).unwrap()), [0, 1, 2]);
+        });
+    }
+}
@@ -0,0 +1,150 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyModule};
+use std::ffi::CStr;
+
+/// Canned users shared by the checks below.
+const SETUP: &CStr = cr#"
+alice = User(1, "Alice", "alice@example.com", 30, True)
+bob = User(2, "Bob", "bob@test.org", 25, False)
+users = [alice, bob]
+"#;
+
+/// One Python expression per exported feature; each must evaluate to `True`.
+const CHECKS: &[(&str, &CStr)] = &[
+    ("add", c"add(2, 3) == 5"),
+    ("multiply", c"multiply(4, 5) == 20"),
+    ("greet", c"greet('Alice') == 'Hello, Alice!'"),
+    ("greet_styled", c"greet_styled('john doe', False, True) == 'Hello, John Doe.'"),
+    ("greet_formal", c"greet_formal('Jane', 'Smith', 'Dr.') == 'Good day, Dr. Jane Smith.'"),
+    ("Calculator", c"Calculator(2.0).add(3.0) == 5.0"),
+    ("User", c"User.from_json(alice.json()).json() == alice.json()"),
+    ("Status", c"alice.status == Status.Active and bob.status == Status.Suspended"),
+    ("Money", c"Money.from_decimal('0.10').add(Money(20)).format() == '0.30 USD'"),
+    ("process_pydantic_users", c"process_pydantic_users(users)[:2] == (30, 1)"),
+    ("process_pyo3_users", c"process_pyo3_users(users)[:2] == (30, 1)"),
+    ("process_pyo3_users_with_ids", c"process_pyo3_users_with_ids(users)[3] == [1]"),
+    ("benchmark_pydantic_process", c"benchmark_pydantic_process(users)['total_age'] == 30"),
+    ("benchmark_pyo3_process", c"benchmark_pyo3_process(users)['total_age'] == 30"),
+    ("dedup_by_id", c"len(dedup_by_id(users + [alice], True)) == 2"),
+    ("validate_email", c"validate_email('alice@example.com') and not validate_email('nope')"),
+    ("validate_emails", c"validate_emails(['nope']) == {'nope': False}"),
+    ("user_size_bytes", c"user_size_bytes() > 0"),
+    ("users_jsonl", c"[u.id for u in users_from_jsonl(users_to_jsonl(users))] == [1, 2]"),
+    ("group_by_field", c"group_by_field(users, 'active')[True]['count'] == 1"),
+    ("group_by_active", c"group_by_active(users) == group_by_field(users, 'active')"),
+    ("CachingGreeter", c"CachingGreeter().greet('Alice') == greet('Alice')"),
+    ("sample_users", c"len(sample_users(users, 1, 42)) == 1"),
+    ("users_from_columns", c"users_from_columns([1], ['A'], ['a@x.com'], [3], [True])[0].active"),
+    ("pseudonymize", c"pseudonymize(users, 'salt')[0].name != 'Alice'"),
+    ("diff_user_lists", c"len(diff_user_lists(users, [alice])['removed']) == 1"),
+    ("top_n_by_age", c"top_n_by_age(users, 1, True)[0].id == 1"),
+    ("aggregate_users", c"aggregate_users(users)['avg_age'] == 27.5"),
+    ("aggregate_users_parallel", c"aggregate_users_parallel(users) == aggregate_users(users)"),
+    ("users_to_table", c"'Alice' in users_to_table(users)"),
+    ("Ema", c"Ema(0.5).push(10.0) == 10.0"),
+    ("coerce_user", c"coerce_user(alice.dict()).json() == alice.json()"),
+    ("distinct_ages", c"distinct_ages(users) == [25, 30]"),
+    ("UserCollection", c"len(UserCollection(users)) == 2"),
+    ("weighted_average_age", c"weighted_average_age(users) == 27.5"),
+    ("paginate_users", c"paginate_users(users, 1, 1)['has_next']"),
+    ("normalize_users", c"normalize_users([User(3, ' Cy ', 'CY@X.COM', 40, True)]) == 1"),
+    ("similarity_matrix", c"similarity_matrix(users)[0][0] == 1.0"),
+    ("users_gzip_json", c"[u.id for u in users_from_gzip_json(users_to_gzip_json(users))] == [1, 2]"),
+    ("filter_users", c"[u.id for u in filter_users(users, lambda u: u.age > 26)] == [1]"),
+    ("UserParser", c"(lambda p: [p.parse(alice.json()) for _ in range(2)] and p.hits() == 1)(UserParser())"),
+    ("total_string_bytes", c"total_string_bytes(users) == 37"),
+    ("is_sorted_by", c"is_sorted_by(users, 'id', False) and not is_sorted_by(users, 'age', False)"),
+    ("domain_frequencies", c"domain_frequencies(users) == {'example.com': 1, 'test.org': 1}"),
+    ("age_category", c"[age_category(a) for a in (17, 18, 64, 65)] == ['minor', 'adult', 'adult', 'senior']"),
+    ("categorize_users", c"categorize_users(users) == ['adult', 'adult']"),
+    ("benchmark_attr_access", c"benchmark_attr_access(alice, 'age', 10)['mean_ns'] > 0"),
+    ("partition_active", c"[[u.id for u in part] for part in partition_active(users)] == [[1], [2]]"),
+    ("batch_checksum", c"batch_checksum(users) == batch_checksum(users[::-1]) != batch_checksum([alice])"),
+    ("median_age", c"median_age(users) == 27.5"),
+    ("contains_pii", c"contains_pii('mail alice@example.com')['emails'] == [(5, 22)]"),
+    ("time_callable", c"time_callable(lambda: None, 3)['iterations'] == 3"),
+    ("Format", c"alice.serialize(Format.Json) == alice.serialize('json') == alice.json()"),
+    ("windowed_active_counts", c"windowed_active_counts(users, 1) == [1, 0]"),
+    ("user_set_ops", c"[len(f(users, [alice])) for f in (users_intersection, users_union, users_difference)] == [1, 2, 1]"),
+    ("timing_overhead_ns", c"0 <= timing_overhead_ns() < 1e6"),
+    ("age_summary", c"age_summary(users) == {'count': 2, 'min': 25, 'max': 30, 'mean': 27.5}"),
+    ("users_to_columnar", c"users_to_columnar(users)['id'] == [1, 2] and alice.to_columnar()['id'] == [1]"),
+    ("from_json_lenient", c"User.from_json_lenient(alice.json()[:-1] + ',}').json() == alice.json()"),
+    ("tag_similarity", c"User(3, 'C', 'c@x.io', 1, True, tags=['a', 'b']).tag_similarity(User(4, 'D', 'd@x.io', 1, True, tags=['b', 'c'])) == 1 / 3"),
+    ("ingest", c"[index for index, _ in ingest([alice.dict(), {'id': 9}, bob.dict()])['errors']] == [1]"),
+    ("Calculator.to_fraction", c"Calculator(0.5).to_fraction(100) == (1, 2) and Calculator(1 / 3).to_fraction(10) == (1, 3)"),
+    ("active_ratio_by_domain", c"active_ratio_by_domain(users + [User(3, 'C', 'c@test.org', 1, True)]) == {'example.com': 1.0, 'test.org': 0.5}"),
+    ("generate_email", c"generate_email('José García', 'example.com') == 'jose.garcia@example.com'"),
+    ("CalculatorSnapshot", c"(snap := (calc := Calculator(2.0)).snapshot()) and calc.add(1.0) == 3.0 and snap.value == 2.0"),
+    ("benchmark_string_hash", c"benchmark_string_hash(['id', 'name', 'email'], 1000)['mean_ns'] > 0"),
+    ("assert_unique_ids", c"assert_unique_ids(users) is None"),
+    ("User.combined_age", c"alice.combined_age(bob) == 55 and alice.age_gap(bob) == bob.age_gap(alice) == 5"),
+    ("NameIndex", c"[u.id for u in NameIndex(users + [User(3, 'alan', 'a@x.io', 1, True)]).with_prefix('AL')] == [3, 1]"),
+    ("User.json_delta", c"alice.json_delta(alice) == '{\"id\":1}' and User(1, 'Alice', 'alice@example.com', 31, True).json_delta(alice) == '{\"age\":31,\"id\":1}'"),
+    ("process_pyo3_users_traced", c"process_pyo3_users_traced(users)['extractions'] == len(users)"),
+    ("Calculator.negate", c"Calculator(-4.0).negate() == 4.0 and Calculator(-4.0).abs() == 4.0 and (-Calculator(2.0)).value == -2.0 and abs(Calculator(-2.0)).value == 2.0"),
+    ("index_by_id", c"index_by_id(users) == {1: alice, 2: bob}"),
+    ("assert_unique_emails", c"assert_unique_emails(users) is None"),
+    ("user_age_stats", c"repr(user_age_stats(users)).startswith('Stats(count=2, sum=55.0, mean=27.5, min=25.0, max=30.0, stddev=3.53')"),
+    ("User.set_age_coerced", c"[(u := User(3, 'C', 'c@x.io', 1, True)).set_age_coerced(v) or u.age for v in (30, 31.0, ' 32 ')] == [30, 31, 32]"),
+    ("count_by", c"count_by(users, lambda u: u.age // 10) == {3: 1, 2: 1}"),
+    ("User.json_canonical", c"alice.json_canonical() == '{\"age\":30,\"email\":\"alice@example.com\",\"id\":1,\"name\":\"Alice\",\"status\":\"active\"}'"),
+];
+
+/// Exercises each exported function and class with canned inputs and reports which ones work.
+///
+/// Every check runs through the module's Python-facing API, so this is a one-call health
+/// probe after import. A check that raises counts as a failure rather than propagating.
+///
+/// # Returns
+///
+/// A dictionary mapping each feature name to `True` (passed) or `False` (failed)
+///
+/// # Errors
+///
+/// Returns an error only if the canned users cannot be set up.
+///
+/// # Examples
+///
+/// ```ignore
+/// all(self_check().values())  # True
+/// ```
+#[pyfunction]
+#[pyo3(pass_module)]
+pub fn self_check<'py>(module: &Bound<'py, PyModule>) -> PyResult<Bound<'py, PyDict>> {
+    let py = module.py();
+    let globals = module.dict().copy()?;
+    py.run(SETUP, Some(&globals), None)?;
+
+    let results = PyDict::new(py);
+    for (feature, expr) in CHECKS {
+        let passed = py
+            .eval(expr, Some(&globals), None)
+            .and_then(|result| result.is_truthy())
+            .unwrap_or(false);
+        results.set_item(feature, passed)?;
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::with_py;
+
+    #[test]
+    fn every_feature_passes() {
+        with_py(|py| {
+            let module = PyModule::new(py, "py_rust_module").unwrap();
+            crate::py_rust_module(&module).unwrap();
+            let results = self_check(&module).unwrap();
+            assert_eq!(results.len(), CHECKS.len());
+            let failed: Vec<String> = results
+                .iter()
+                .filter(|(_, passed)| !passed.is_truthy().unwrap())
+                .map(|(feature, _)| feature.to_string())
+                .collect();
+            assert!(failed.is_empty(), "failing self-checks: {:?}", failed);
+        });
+    }
+}
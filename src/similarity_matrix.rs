@@ -0,0 +1,103 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Returns the Levenshtein edit distance between `a` and `b`, counted in characters.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    // `row[j]` holds the distance between the prefix of `a` seen so far and `b[..j]`.
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Scores how alike two names are, from 0.0 (nothing in common) to 1.0 (identical).
+///
+/// The score is `1 - distance / longer_length`, using the Levenshtein distance in characters.
+/// Two empty names are identical.
+pub(crate) fn name_similarity(a: &str, b: &str) -> f64 {
+    let longer = a.chars().count().max(b.chars().count());
+    if longer == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(a, b) as f64 / longer as f64
+}
+
+/// Computes the pairwise name similarity of a list of users.
+///
+/// Entry `[i][j]` is the `name_similarity` of user `i` and user `j`. The matrix is symmetric
+/// with 1.0 on the diagonal.
+///
+/// This compares every pair of users, so its cost grows as O(N²) in the number of users
+/// (times the cost of comparing two names); keep N modest.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Examples
+///
+/// ```ignore
+/// similarity_matrix([User(1, "Anna", ...), User(2, "Hanna", ...)])  # [[1.0, 0.6], [0.6, 1.0]]
+/// ```
+#[pyfunction]
+pub fn similarity_matrix(users: Vec<PyRef<User>>) -> Vec<Vec<f64>> {
+    let n = users.len();
+    let mut matrix = vec![vec![1.0; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let similarity = name_similarity(&users[i].name, &users[j].name);
+            matrix[i][j] = similarity;
+            matrix[j][i] = similarity;
+        }
+    }
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+
+    #[test]
+    fn levenshtein_counts_edits() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("José", "Jose"), 1);
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn name_similarity_is_normalized() {
+        assert_eq!(name_similarity("", ""), 1.0);
+        assert_eq!(name_similarity("abcd", "abce"), 0.75);
+        assert_eq!(name_similarity("ab", "cd"), 0.0);
+    }
+
+    #[test]
+    fn matrix_is_symmetric_with_a_unit_diagonal() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                ["Alice", "Alicia", "Bob", ""].into_iter().map(|name| user(1, name, "u@x.com", 30, true)).collect(),
+            );
+            let matrix = similarity_matrix(borrow_all(&users));
+            assert_eq!(matrix.len(), 4);
+            for (i, row) in matrix.iter().enumerate() {
+                assert_eq!(row[i], 1.0);
+                for (j, &similarity) in row.iter().enumerate() {
+                    assert_eq!(similarity, matrix[j][i]);
+                }
+            }
+            assert_eq!(matrix[0][1], name_similarity("Alice", "Alicia"));
+        });
+    }
+}
@@ -0,0 +1,78 @@
+use pyo3::prelude::*;
+use pyo3::types::PyFloat;
+
+/// Summary statistics of a sample, as returned by `user_age_stats`.
+///
+/// `mean`, `min`, and `max` are NaN for an empty sample, and `stddev` (the sample standard
+/// deviation, dividing by N - 1) is NaN for fewer than two values.
+#[pyclass(frozen, skip_from_py_object)]
+#[derive(Clone)]
+pub struct Stats {
+    #[pyo3(get)]
+    pub count: usize,
+    #[pyo3(get)]
+    pub sum: f64,
+    #[pyo3(get)]
+    pub mean: f64,
+    #[pyo3(get)]
+    pub min: f64,
+    #[pyo3(get)]
+    pub max: f64,
+    #[pyo3(get)]
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Computes the statistics of `values` in two passes: one for the count, sum, and range,
+    /// and one for the squared deviations from the mean.
+    pub(crate) fn from_values(values: &[f64]) -> Self {
+        let count = values.len();
+        let sum = values.iter().fold(0.0, |total, v| total + v);
+        let mean = if count == 0 { f64::NAN } else { sum / count as f64 };
+        let (min, max) = if count == 0 {
+            (f64::NAN, f64::NAN)
+        } else {
+            values
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| (min.min(v), max.max(v)))
+        };
+        let stddev = if count < 2 {
+            f64::NAN
+        } else {
+            let squared: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+            (squared / (count - 1) as f64).sqrt()
+        };
+        Stats {
+            count,
+            sum,
+            mean,
+            min,
+            max,
+            stddev,
+        }
+    }
+}
+
+#[pymethods]
+impl Stats {
+    /// Formats every field like a dataclass repr, with floats as Python prints them.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// repr(user_age_stats(users))
+    /// # "Stats(count=2, sum=55.0, mean=27.5, min=25.0, max=30.0, stddev=3.5355339059327378)"
+    /// ```
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let float = |v: f64| PyFloat::new(py, v).repr().map(|repr| repr.to_string());
+        Ok(format!(
+            "Stats(count={}, sum={}, mean={}, min={}, max={}, stddev={})",
+            self.count,
+            float(self.sum)?,
+            float(self.mean)?,
+            float(self.min)?,
+            float(self.max)?,
+            float(self.stddev)?
+        ))
+    }
+}
@@ -0,0 +1,70 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The lifecycle state of a User, serialized as a lowercase string (e.g. `"suspended"`)
+#[pyclass(eq, eq_int, from_py_object)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Active,
+    Suspended,
+    Deleted,
+}
+
+impl Status {
+    /// Returns the lowercase name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Active => "active",
+            Status::Suspended => "suspended",
+            Status::Deleted => "deleted",
+        }
+    }
+
+    /// Parses the lowercase name produced by `as_str`.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "active" => Some(Status::Active),
+            "suspended" => Some(Status::Suspended),
+            "deleted" => Some(Status::Deleted),
+            _ => None,
+        }
+    }
+
+    /// Maps a legacy `active` flag onto a status: `true` is `Active`, `false` is `Suspended`.
+    pub fn from_active(active: bool) -> Self {
+        if active {
+            Status::Active
+        } else {
+            Status::Suspended
+        }
+    }
+}
+
+#[pymethods]
+impl Status {
+    /// Returns the lowercase name of the status.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// str(Status.Suspended)  # "suspended"
+    /// ```
+    fn __str__(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_round_trips_as_str() {
+        for status in [Status::Active, Status::Suspended, Status::Deleted] {
+            assert_eq!(Status::parse(status.as_str()), Some(status));
+        }
+        assert_eq!(Status::parse("Active"), None);
+        assert_eq!(Status::from_active(false), Status::Suspended);
+    }
+}
@@ -0,0 +1,60 @@
+//! Fixtures shared by the unit tests.
+
+use pyo3::prelude::*;
+use std::ffi::CStr;
+
+use crate::status::Status;
+use crate::user::{User, DEFAULT_WEIGHT};
+
+/// Builds a user the way `User(id, name, email, age, active)` does from Python.
+pub(crate) fn user(id: i32, name: &str, email: &str, age: i32, active: bool) -> User {
+    User {
+        id,
+        name: name.to_string(),
+        email: email.to_string(),
+        age,
+        status: Status::from_active(active),
+        birthdate: None,
+        weight: DEFAULT_WEIGHT,
+        tags: Vec::new(),
+    }
+}
+
+/// Moves `users` into Python objects, as if they had been passed in from a Python list.
+pub(crate) fn bind(py: Python<'_>, users: Vec<User>) -> Vec<Bound<'_, User>> {
+    users
+        .into_iter()
+        .map(|user| Bound::new(py, user).expect("User is constructible"))
+        .collect()
+}
+
+/// Borrows every bound user, matching a `Vec<PyRef<User>>` argument.
+pub(crate) fn borrow_all<'py>(users: &[Bound<'py, User>]) -> Vec<PyRef<'py, User>> {
+    users.iter().map(|user| user.borrow()).collect()
+}
+
+/// Runs `f` attached to the embedded interpreter, starting it on first use.
+pub(crate) fn with_py<F, R>(f: F) -> R
+where
+    F: for<'py> FnOnce(Python<'py>) -> R,
+{
+    Python::initialize();
+    Python::attach(f)
+}
+
+/// Returns the message of a Python exception without its type prefix.
+pub(crate) fn message(err: PyErr) -> String {
+    with_py(|py| err.value(py).to_string())
+}
+
+/// Asserts that `actual` equals the value of the Python expression `expected`.
+pub(crate) fn assert_py_eq(actual: &Bound<'_, PyAny>, expected: &CStr) {
+    let py = actual.py();
+    let expected = py.eval(expected, None, None).expect("expected value is a valid expression");
+    assert!(
+        actual.eq(&expected).expect("values are comparable"),
+        "{} != {}",
+        actual.repr().unwrap(),
+        expected.repr().unwrap()
+    );
+}
@@ -0,0 +1,39 @@
+use pyo3::prelude::*;
+use std::time::Instant;
+
+/// Number of back-to-back `Instant::now()` pairs averaged by `timing_overhead_ns`.
+const ITERATIONS: u32 = 100_000;
+
+/// Measures the average cost, in nanoseconds, of taking a timestamp with `Instant::now()`.
+///
+/// The benchmark functions in this module time their work with `Instant`, so each
+/// measurement includes roughly this much overhead; subtract it when timing very short
+/// operations.
+///
+/// # Examples
+///
+/// ```ignore
+/// timing_overhead_ns()  # e.g. 20.5
+/// ```
+#[pyfunction]
+pub fn timing_overhead_ns() -> f64 {
+    let mut total_ns: u128 = 0;
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        let end = Instant::now();
+        total_ns += end.duration_since(start).as_nanos();
+    }
+    total_ns as f64 / ITERATIONS as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overhead_is_small_but_non_negative() {
+        let overhead = timing_overhead_ns();
+        // Generous bound so a loaded CI machine does not make this flaky.
+        assert!((0.0..1_000_000.0).contains(&overhead), "{}", overhead);
+    }
+}
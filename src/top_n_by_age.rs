@@ -0,0 +1,67 @@
+use pyo3::prelude::*;
+use std::cmp::Ordering;
+
+use crate::user::User;
+
+/// Returns the `n` oldest (or youngest) users, ranked by age with ties broken by ascending id.
+///
+/// Uses a partial sort: the top `n` are selected in linear time and only those are sorted,
+/// so large lists are never fully sorted.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+/// * `n` - How many users to return; all users are returned if `n` exceeds the list length
+/// * `oldest` - Rank by greatest age when true, by least age when false
+///
+/// # Examples
+///
+/// ```ignore
+/// [u.name for u in top_n_by_age(users, 2, True)]
+/// ```
+#[pyfunction]
+pub fn top_n_by_age(mut users: Vec<User>, n: usize, oldest: bool) -> Vec<User> {
+    let rank = |a: &User, b: &User| -> Ordering {
+        let by_age = if oldest { b.age.cmp(&a.age) } else { a.age.cmp(&b.age) };
+        by_age.then(a.id.cmp(&b.id))
+    };
+
+    if n == 0 {
+        return Vec::new();
+    }
+    if n < users.len() {
+        users.select_nth_unstable_by(n - 1, rank);
+        users.truncate(n);
+    }
+    users.sort_unstable_by(rank);
+    users
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::user;
+
+    fn ids(n: usize, oldest: bool) -> Vec<i32> {
+        let users = vec![
+            user(1, "Alice", "alice@example.com", 30, true),
+            user(2, "Bob", "bob@example.com", 52, true),
+            user(3, "Carol", "carol@example.com", 19, true),
+            user(4, "Dan", "dan@example.com", 52, true),
+            user(5, "Eve", "eve@example.com", 41, true),
+        ];
+        top_n_by_age(users, n, oldest).iter().map(|u| u.id).collect()
+    }
+
+    #[test]
+    fn returns_the_two_oldest_with_ties_by_id() {
+        assert_eq!(ids(2, true), [2, 4]);
+        assert_eq!(ids(2, false), [3, 1]);
+    }
+
+    #[test]
+    fn handles_n_of_zero_and_past_the_end() {
+        assert!(ids(0, true).is_empty());
+        assert_eq!(ids(10, true), [2, 4, 5, 1, 3]);
+    }
+}
@@ -0,0 +1,43 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Sums the UTF-8 byte lengths of every user's `name` and `email`.
+///
+/// These bytes live on the heap rather than inline in the `User` struct (see `user_size_bytes`),
+/// and they are what gets copied whenever users cross the Python/Rust boundary by value.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Examples
+///
+/// ```ignore
+/// total_string_bytes([User(1, "Ann", "a@b.io", 30, True)])  # 9
+/// ```
+#[pyfunction]
+pub fn total_string_bytes(users: Vec<PyRef<User>>) -> usize {
+    users.iter().map(|user| user.name.len() + user.email.len()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+
+    #[test]
+    fn sums_name_and_email_bytes() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![
+                    user(1, "Alice", "a@x.com", 30, true),  // 5 + 7
+                    user(2, "José", "jose@x.com", 25, true), // 5 + 10: "é" is two bytes
+                ],
+            );
+            assert_eq!(total_string_bytes(borrow_all(&users)), 27);
+            assert_eq!(total_string_bytes(Vec::new()), 0);
+        });
+    }
+}
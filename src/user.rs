@@ -1,10 +1,27 @@
+use chrono::{Local, NaiveDate};
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyString};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+use crate::format::Format;
+use crate::status::Status;
+use crate::users_to_columnar::columnar_dict;
+use crate::validate_email::validate_email;
+
+/// Largest age accepted by `User.validate`.
+///
+/// Any non-negative `i32` is accepted, so age arithmetic such as `combined_age` has to cope
+/// with ages right up to `i32::MAX`.
+pub(crate) const MAX_AGE: i32 = i32::MAX;
 
 /// A User model with JSON serialization support (Pydantic-like)
-#[pyclass(skip_from_py_object)]
+#[pyclass(from_py_object)]
 #[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "UserRecord")]
 pub struct User {
     #[pyo3(get)]
     pub id: i32,
@@ -15,12 +32,576 @@ pub struct User {
     #[pyo3(get, set)]
     pub age: i32,
     #[pyo3(get, set)]
-    pub active: bool,
+    pub status: Status,
+    #[pyo3(get, set)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birthdate: Option<String>,
+    /// Relative weight used by weighted aggregates; omitted from JSON when it is the default 1.0.
+    #[pyo3(get, set)]
+    #[serde(skip_serializing_if = "is_default_weight")]
+    pub weight: f64,
+    /// Free-form labels such as `"admin"` or `"beta"`; omitted from JSON when empty.
+    #[pyo3(get, set)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// Weight given to users that do not specify one.
+pub(crate) const DEFAULT_WEIGHT: f64 = 1.0;
+
+fn default_weight() -> f64 {
+    DEFAULT_WEIGHT
+}
+
+fn is_default_weight(weight: &f64) -> bool {
+    *weight == DEFAULT_WEIGHT
+}
+
+/// The accepted JSON shape of a `User`.
+///
+/// Payloads written before `status` existed carry a boolean `active` instead; it is
+/// mapped through `Status::from_active`. When both are present, `status` wins.
+#[derive(Deserialize)]
+struct UserRecord {
+    id: i32,
+    name: String,
+    email: String,
+    age: i32,
+    #[serde(default)]
+    status: Option<Status>,
+    #[serde(default)]
+    active: Option<bool>,
+    #[serde(default)]
+    birthdate: Option<String>,
+    #[serde(default = "default_weight")]
+    weight: f64,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl TryFrom<UserRecord> for User {
+    type Error = String;
+
+    fn try_from(record: UserRecord) -> Result<Self, Self::Error> {
+        let status = match (record.status, record.active) {
+            (Some(status), _) => status,
+            (None, Some(active)) => Status::from_active(active),
+            (None, None) => return Err("missing field `status`".to_string()),
+        };
+        Ok(User {
+            id: record.id,
+            name: record.name,
+            email: record.email,
+            age: record.age,
+            status,
+            birthdate: record.birthdate,
+            weight: record.weight,
+            tags: record.tags,
+        })
+    }
+}
+
+/// The JSON shape used by `User.json_legacy_bool`, for consumers that predate `status`
+/// and expect `active` as `1`/`0`.
+#[derive(Serialize, Deserialize)]
+struct LegacyBoolUser {
+    id: i32,
+    name: String,
+    email: String,
+    age: i32,
+    #[serde(serialize_with = "serialize_bool_as_int", deserialize_with = "deserialize_bool_or_int")]
+    active: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    birthdate: Option<String>,
+    #[serde(default = "default_weight", skip_serializing_if = "is_default_weight")]
+    weight: f64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+}
+
+impl From<&User> for LegacyBoolUser {
+    fn from(user: &User) -> Self {
+        LegacyBoolUser {
+            id: user.id,
+            name: user.name.clone(),
+            email: user.email.clone(),
+            age: user.age,
+            active: user.is_active(),
+            birthdate: user.birthdate.clone(),
+            weight: user.weight,
+            tags: user.tags.clone(),
+        }
+    }
+}
+
+impl From<LegacyBoolUser> for User {
+    fn from(record: LegacyBoolUser) -> Self {
+        User {
+            id: record.id,
+            name: record.name,
+            email: record.email,
+            age: record.age,
+            status: Status::from_active(record.active),
+            birthdate: record.birthdate,
+            weight: record.weight,
+            tags: record.tags,
+        }
+    }
+}
+
+fn serialize_bool_as_int<S: serde::Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(u8::from(*value))
+}
+
+/// Accepts `true`/`false` as well as the integers `1`/`0`.
+fn deserialize_bool_or_int<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+    struct BoolOrIntVisitor;
+
+    impl serde::de::Visitor<'_> for BoolOrIntVisitor {
+        type Value = bool;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a boolean or the integer 0 or 1")
+        }
+
+        fn visit_bool<E: serde::de::Error>(self, value: bool) -> Result<bool, E> {
+            Ok(value)
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<bool, E> {
+            match value {
+                0 => Ok(false),
+                1 => Ok(true),
+                _ => Err(E::invalid_value(serde::de::Unexpected::Unsigned(value), &self)),
+            }
+        }
+
+        fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<bool, E> {
+            match u64::try_from(value) {
+                Ok(value) => self.visit_u64(value),
+                Err(_) => Err(E::invalid_value(serde::de::Unexpected::Signed(value), &self)),
+            }
+        }
+    }
+
+    deserializer.deserialize_any(BoolOrIntVisitor)
+}
+
+/// Returns the lowercase hex encoding of `bytes`.
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    hex
+}
+
+/// Returns the lowercase hex encoding of the SHA-256 digest of `data`.
+pub(crate) fn sha256_hex(data: &[u8]) -> String {
+    hex_encode(&Sha256::digest(data))
+}
+
+/// Returns the number of bytes `serde_json` emits for `s` as a JSON string, including the quotes.
+///
+/// Mirrors serde_json's escaping: `"` and `\` and the short control escapes (`\b`, `\f`,
+/// `\n`, `\r`, `\t`) take two bytes, other control characters take six (`\u00XX`), and
+/// everything else is copied through as UTF-8.
+fn json_string_len(s: &str) -> usize {
+    2 + s
+        .bytes()
+        .map(|b| match b {
+            b'"' | b'\\' | 0x08 | 0x0c | b'\n' | b'\r' | b'\t' => 2,
+            0x00..=0x1f => 6,
+            _ => 1,
+        })
+        .sum::<usize>()
+}
+
+/// An `io::Write` sink that only counts the bytes written to it.
+struct ByteCounter(usize);
+
+impl std::io::Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns the number of bytes `serde_json` emits for `x`, without allocating.
+fn json_float_len(x: f64) -> usize {
+    let mut counter = ByteCounter(0);
+    // Writing a float to an in-memory counter cannot fail.
+    let _ = serde_json::to_writer(&mut counter, &x);
+    counter.0
+}
+
+/// Returns the number of decimal characters (including any `-` sign) needed to print `n`.
+fn json_int_len(n: i32) -> usize {
+    let sign = usize::from(n < 0);
+    sign + n.unsigned_abs().checked_ilog10().map_or(1, |digits| digits as usize + 1)
+}
+
+/// Positional layout of a `User` in the bincode encoding.
+///
+/// bincode is not self-describing, so fields that the JSON form may omit (such as
+/// `birthdate`, `weight`, and `tags`) are always written here.
+type BincodeRecord = (i32, String, String, i32, Status, Option<String>, f64, Vec<String>);
+
+/// Computes the number of whole days between `birthdate` (an ISO `YYYY-MM-DD` date) and `today`.
+///
+/// Kept separate from `User::age_in_days` so the reference date can be fixed.
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `birthdate` is missing, is not a valid ISO date, or lies after `today`.
+fn days_since_birthdate(birthdate: Option<&str>, today: NaiveDate) -> PyResult<i64> {
+    let birthdate = birthdate.ok_or_else(|| pyo3::exceptions::PyValueError::new_err("birthdate is not set"))?;
+    let date = NaiveDate::parse_from_str(birthdate, "%Y-%m-%d")
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("invalid birthdate '{}': {}", birthdate, e)))?;
+    if date > today {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "birthdate '{}' is in the future",
+            birthdate
+        )));
+    }
+    Ok((today - date).num_days())
+}
+
+/// Encodes a status as the single flag byte of `User.to_fixed_record`.
+///
+/// `1` and `0` match the legacy active flag; `2` marks a deleted user.
+fn status_record_byte(status: Status) -> u8 {
+    match status {
+        Status::Suspended => 0,
+        Status::Active => 1,
+        Status::Deleted => 2,
+    }
+}
+
+/// Reads a `User.to_fixed_record` layout from the front of a byte slice.
+struct FixedRecordReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> FixedRecordReader<'a> {
+    /// Splits off the next `n` bytes, naming `what` in the error if too few remain.
+    fn take(&mut self, n: usize, what: &str) -> PyResult<&'a [u8]> {
+        if self.data.len() < n {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "truncated fixed record: expected {} bytes for {}, found {}",
+                n,
+                what,
+                self.data.len()
+            )));
+        }
+        let (head, rest) = self.data.split_at(n);
+        self.data = rest;
+        Ok(head)
+    }
+
+    fn read_i32(&mut self, what: &str) -> PyResult<i32> {
+        let bytes = self.take(4, what)?;
+        Ok(i32::from_le_bytes(bytes.try_into().expect("take returned 4 bytes")))
+    }
+
+    fn read_string(&mut self, what: &str) -> PyResult<String> {
+        let len = u32::from_le_bytes(self.take(4, what)?.try_into().expect("take returned 4 bytes"));
+        let bytes = self.take(len as usize, what)?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| pyo3::exceptions::PyValueError::new_err(format!("{} is not valid UTF-8", what)))
+    }
+}
+
+/// Appends `s` to `out` as a little-endian `u32` byte length followed by its UTF-8 bytes.
+fn write_record_string(out: &mut Vec<u8>, s: &str, what: &str) -> PyResult<()> {
+    let len = u32::try_from(s.len())
+        .map_err(|_| pyo3::exceptions::PyValueError::new_err(format!("{} is too long for a fixed record", what)))?;
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+/// Looks up variable `key` via `lookup` and parses it, returning `fallback` when it is unset.
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if the variable is set but cannot be parsed as a `T`.
+fn env_or<T: std::str::FromStr>(lookup: &impl Fn(&str) -> Option<String>, key: &str, fallback: T) -> PyResult<T> {
+    match lookup(key) {
+        Some(raw) => raw
+            .parse()
+            .map_err(|_| pyo3::exceptions::PyValueError::new_err(format!("invalid value '{}' for {}", raw, key))),
+        None => Ok(fallback),
+    }
+}
+
+/// Deserializes `json_str` into `T`, prefixing any error with the path of the offending field.
+///
+/// A type mismatch on `age` is reported as `age: invalid type: string "x", expected i32 at line 1 column 9`,
+/// and errors inside arrays include the element index (e.g. `[1].age: ...`).
+///
+/// # Errors
+///
+/// Returns a `PyValueError` carrying the field path and the underlying serde error.
+pub(crate) fn parse_json<T: DeserializeOwned>(json_str: &str) -> PyResult<T> {
+    let mut de = serde_json::Deserializer::from_str(json_str);
+    let value = serde_path_to_error::deserialize(&mut de).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    de.end().map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    Ok(value)
+}
+
+/// Removes every comma that is followed, after optional whitespace, by `}` or `]`.
+///
+/// Commas inside string literals are left alone, including strings containing escaped quotes.
+fn strip_trailing_commas(json_str: &str) -> String {
+    let mut out = String::with_capacity(json_str.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in json_str.char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let next = json_str[i + 1..].trim_start().chars().next();
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Converts a float age to an integer, rejecting NaN, infinities, and fractional values.
+///
+/// Returns `Ok(None)` for integral values too large for an `i64`.
+fn integral_age(age: f64) -> PyResult<Option<i64>> {
+    if !age.is_finite() || age.fract() != 0.0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "age must be an integral value, got {}",
+            age
+        )));
+    }
+    Ok((age.abs() < i64::MAX as f64).then_some(age as i64))
+}
+
+/// Top-level field names of a User, as accepted by `apply_json_patch`, `json_excluding`, and `select`.
+const FIELD_NAMES: [&str; 8] = ["id", "name", "email", "age", "status", "birthdate", "weight", "tags"];
+
+/// A single RFC 6902 operation as accepted by `User.apply_json_patch`.
+#[derive(Deserialize)]
+struct PatchOperation {
+    op: String,
+    path: String,
+    #[serde(default, deserialize_with = "deserialize_present")]
+    value: Option<serde_json::Value>,
+}
+
+/// Wraps any present value, including `null`, in `Some`; only a missing key stays `None`.
+fn deserialize_present<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<serde_json::Value>, D::Error> {
+    serde_json::Value::deserialize(deserializer).map(Some)
+}
+
+/// Resolves a JSON Pointer such as `/email` in operation `index` to one of `FIELD_NAMES`.
+///
+/// The id identifies the user and cannot be patched.
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if the pointer is not a single segment naming a User field other
+/// than `id`.
+fn patch_field(index: usize, path: &str) -> PyResult<String> {
+    let invalid = || pyo3::exceptions::PyValueError::new_err(format!("[{}]: invalid patch path '{}'", index, path));
+    let segment = path.strip_prefix('/').ok_or_else(invalid)?;
+    if segment.contains('/') {
+        return Err(invalid());
+    }
+    let field = segment.replace("~1", "/").replace("~0", "~");
+    if field == "id" || !FIELD_NAMES.contains(&field.as_str()) {
+        return Err(invalid());
+    }
+    Ok(field)
+}
+
+impl User {
+    /// Builds a User from a Python dict with the same keys as `dict()`.
+    ///
+    /// `id`, `name`, `email`, and `age` are required. The status comes from `status`
+    /// (a `Status` or its lowercase name) or, failing that, from a boolean `active`.
+    /// `birthdate` is optional and may be `None`; `weight` defaults to 1.0 and `tags` to empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` for missing keys or unknown status names, and propagates
+    /// extraction errors for values of the wrong type.
+    pub(crate) fn from_py_dict(dict: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let required = |key: &str| -> PyResult<Bound<'_, PyAny>> {
+            dict.get_item(key)?
+                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("missing field `{}`", key)))
+        };
+        let status = match (dict.get_item("status")?, dict.get_item("active")?) {
+            (Some(status), _) => match status.extract::<Status>() {
+                Ok(status) => status,
+                Err(_) => {
+                    let name: String = status.extract()?;
+                    Status::parse(&name).ok_or_else(|| {
+                        pyo3::exceptions::PyValueError::new_err(format!("unknown status '{}'", name))
+                    })?
+                }
+            },
+            (None, Some(active)) => Status::from_active(active.extract()?),
+            (None, None) => return Err(pyo3::exceptions::PyValueError::new_err("missing field `status`")),
+        };
+        Ok(User {
+            id: required("id")?.extract()?,
+            name: required("name")?.extract()?,
+            email: required("email")?.extract()?,
+            age: required("age")?.extract()?,
+            status,
+            birthdate: match dict.get_item("birthdate")? {
+                Some(birthdate) => birthdate.extract()?,
+                None => None,
+            },
+            weight: match dict.get_item("weight")? {
+                Some(weight) => weight.extract()?,
+                None => DEFAULT_WEIGHT,
+            },
+            tags: match dict.get_item("tags")? {
+                Some(tags) => tags.extract()?,
+                None => Vec::new(),
+            },
+        })
+    }
+
+    /// Returns the reason the user fails validation, or `None` if it is valid.
+    ///
+    /// Checks, in order: the name is not blank, the email passes `validate_email`,
+    /// the age is between 0 and `MAX_AGE`, and the weight is finite and non-negative.
+    pub(crate) fn validation_error(&self) -> Option<String> {
+        if self.name.trim().is_empty() {
+            return Some("name must not be empty".to_string());
+        }
+        if !validate_email(&self.email) {
+            return Some(format!("invalid email '{}'", self.email));
+        }
+        if !(0..=MAX_AGE).contains(&self.age) {
+            return Some(format!("age {} is out of range 0..={}", self.age, MAX_AGE));
+        }
+        if !(self.weight.is_finite() && self.weight >= 0.0) {
+            return Some(format!("weight {} must be a non-negative finite number", self.weight));
+        }
+        None
+    }
+
+    /// Returns true when the user's status is `Active`.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.status == Status::Active
+    }
+
+    /// Returns the status implied by setting the legacy `active` flag.
+    ///
+    /// `true` always means `Active`. `false` suspends an active user but leaves an
+    /// already inactive status (such as `Deleted`) untouched.
+    fn status_for_active(&self, active: bool) -> Status {
+        match (active, self.status) {
+            (true, _) => Status::Active,
+            (false, Status::Active) => Status::Suspended,
+            (false, status) => status,
+        }
+    }
+
+    /// Returns the lowercased part of the email after the last `@`, or `None` if there is no `@`.
+    pub(crate) fn email_domain(&self) -> Option<String> {
+        self.email.rsplit_once('@').map(|(_, domain)| domain.to_lowercase())
+    }
+
+    /// Returns the raw SHA-256 digest of the user's canonical JSON, as hex-encoded by `content_hash`.
+    pub(crate) fn content_digest(&self) -> PyResult<[u8; 32]> {
+        Ok(Sha256::digest(self.canonical_json()?.as_bytes()).into())
+    }
+
+    /// Trims the name and collapses internal runs of whitespace to single spaces, and trims
+    /// and lowercases the email.
+    ///
+    /// Returns true if either field changed.
+    pub(crate) fn normalize(&mut self) -> bool {
+        let name = self.name.split_whitespace().collect::<Vec<_>>().join(" ");
+        let email = self.email.trim().to_lowercase();
+        let changed = name != self.name || email != self.email;
+        self.name = name;
+        self.email = email;
+        changed
+    }
+
+    /// Serializes the user with `serde_json` and returns the resulting object.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if serialization fails or does not produce a JSON object.
+    fn json_object(&self) -> PyResult<serde_json::Map<String, serde_json::Value>> {
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::Object(fields)) => Ok(fields),
+            Ok(other) => Err(pyo3::exceptions::PyValueError::new_err(format!("expected a JSON object, got {}", other))),
+            Err(e) => Err(pyo3::exceptions::PyValueError::new_err(e.to_string())),
+        }
+    }
+
+    /// Returns every field as a JSON object, including the optional fields that `json()` omits
+    /// when they hold their defaults.
+    fn json_fields(&self) -> PyResult<serde_json::Map<String, serde_json::Value>> {
+        let mut fields = self.json_object()?;
+        fields.insert("birthdate".to_string(), serde_json::json!(self.birthdate));
+        fields.insert("weight".to_string(), serde_json::json!(self.weight));
+        fields.insert("tags".to_string(), serde_json::json!(self.tags));
+        Ok(fields)
+    }
+
+    /// Serializes the user to JSON with object keys in sorted order and no insignificant whitespace.
+    ///
+    /// The output depends only on the field values, not on struct field order.
+    pub(crate) fn canonical_json(&self) -> PyResult<String> {
+        let fields: BTreeMap<String, serde_json::Value> = self.json_object()?.into_iter().collect();
+        serde_json::to_string(&fields).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Builds the `with_defaults` user, reading the `DEFAULT_USER_*` variables through `lookup`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `DEFAULT_USER_ID` or `DEFAULT_USER_AGE` is set but is not an integer.
+    fn defaults_from(lookup: impl Fn(&str) -> Option<String>) -> PyResult<Self> {
+        Ok(User {
+            id: env_or(&lookup, "DEFAULT_USER_ID", 1)?,
+            name: env_or(&lookup, "DEFAULT_USER_NAME", "Test User".to_string())?,
+            email: env_or(&lookup, "DEFAULT_USER_EMAIL", "test@example.com".to_string())?,
+            age: env_or(&lookup, "DEFAULT_USER_AGE", 30)?,
+            status: Status::Active,
+            birthdate: None,
+            weight: DEFAULT_WEIGHT,
+            tags: Vec::new(),
+        })
+    }
 }
 
 #[pymethods]
 impl User {
-    /// Creates a new User with the provided id, name, email, age, and active flag.
+    /// Creates a new User with the provided id, name, email, age, active flag, and optional birthdate.
+    ///
+    /// `birthdate` is an ISO `YYYY-MM-DD` date string and defaults to `None`. The status is
+    /// derived from `active` (`Active` or `Suspended`) unless `status` is given explicitly.
+    /// `weight` is used by weighted aggregates and defaults to 1.0; `tags` defaults to empty.
     ///
     /// # Examples
     ///
@@ -28,10 +609,81 @@ impl User {
     /// user = User(1, "Alice", "alice@example.com", 30, True)
     /// user.id
     /// user.name
+    /// user = User(1, "Alice", "alice@example.com", 30, True, birthdate="1994-05-17")
+    /// user = User(1, "Alice", "alice@example.com", 30, False, status=Status.Deleted)
+    /// user = User(1, "Alice", "alice@example.com", 30, True, tags=["admin", "beta"])
     /// ```
     #[new]
-    fn new(id: i32, name: String, email: String, age: i32, active: bool) -> Self {
-        User { id, name, email, age, active }
+    #[pyo3(signature = (id, name, email, age, active, birthdate=None, status=None, weight=DEFAULT_WEIGHT, tags=Vec::new()))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        id: i32,
+        name: String,
+        email: String,
+        age: i32,
+        active: bool,
+        birthdate: Option<String>,
+        status: Option<Status>,
+        weight: f64,
+        tags: Vec<String>,
+    ) -> Self {
+        let status = status.unwrap_or_else(|| Status::from_active(active));
+        User {
+            id,
+            name,
+            email,
+            age,
+            status,
+            birthdate,
+            weight,
+            tags,
+        }
+    }
+
+    /// Whether the user's status is `Active`; kept for compatibility with the former boolean field.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User.from_json('{"id":1,"name":"Alice","email":"alice@example.com","age":30,"status":"suspended"}')
+    /// user.active  # False
+    /// ```
+    #[getter]
+    fn active(&self) -> bool {
+        self.is_active()
+    }
+
+    /// Sets the status from a boolean: `True` activates the user, `False` suspends an active user
+    /// and leaves other inactive statuses unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user.active = False
+    /// user.status  # Status.Suspended
+    /// ```
+    #[setter]
+    fn set_active(&mut self, active: bool) {
+        self.status = self.status_for_active(active);
+    }
+
+    /// The lowercased domain part of the user's email (everything after the `@`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if the email does not contain an `@`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@Example.com", 30, True)
+    /// user.domain  # "example.com"
+    /// ```
+    #[getter]
+    fn domain(&self) -> PyResult<String> {
+        self.email_domain().ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!("email '{}' does not contain '@'", self.email))
+        })
     }
 
     /// Serialize the User to a compact JSON string.
@@ -50,9 +702,12 @@ impl User {
         serde_json::to_string(self).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
     }
 
-    /// Serialize the user to a pretty-printed JSON string.
+    /// Serialize the User to canonical JSON: object keys in alphabetical order and no
+    /// insignificant whitespace.
     ///
-    /// Produces a human-readable, pretty-formatted JSON representation of the `User`.
+    /// The output is byte-stable regardless of struct field order, which makes it suitable
+    /// for hashing and diffing; `content_hash` is the SHA-256 of this string. As with `json()`,
+    /// optional fields holding their defaults are omitted.
     ///
     /// # Errors
     ///
@@ -62,91 +717,1352 @@ impl User {
     ///
     /// ```ignore
     /// user = User(1, "Alice", "alice@example.com", 30, True)
-    /// s = user.json_pretty()
-    /// '\n' in s
+    /// user.json_canonical()
+    /// # '{"age":30,"email":"alice@example.com","id":1,"name":"Alice","status":"active"}'
     /// ```
-    fn json_pretty(&self) -> PyResult<String> {
-        serde_json::to_string_pretty(self).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    fn json_canonical(&self) -> PyResult<String> {
+        self.canonical_json()
     }
 
-    /// Creates a User instance from a JSON string.
+    /// Serialize the User to compact JSON with the named fields left out.
     ///
-    /// Parses `json_str` and returns the corresponding `User` value.
+    /// Useful for redacting fields such as `email` before logging. Optional fields that are
+    /// already omitted from `json()` may be listed without effect. The remaining keys are
+    /// written in sorted order rather than field order.
     ///
     /// # Errors
     ///
-    /// Returns a `PyValueError` if `json_str` is not valid JSON or does not match the `User` schema.
+    /// Returns a `PyValueError` if `fields` contains `"id"`, which is always required, or a
+    /// name that is not a User field.
     ///
     /// # Examples
     ///
     /// ```ignore
-    /// json_str = '{"id":1,"name":"Alice","email":"alice@example.com","age":30,"active":true}'
-    /// user = User.from_json(json_str)
-    /// user.id
-    /// user.name
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// user.json_excluding(["email", "age"])  # '{"id":1,"name":"Alice","status":"active"}'
     /// ```
-    #[staticmethod]
-    fn from_json(json_str: String) -> PyResult<Self> {
-        serde_json::from_str(&json_str).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    fn json_excluding(&self, fields: Vec<String>) -> PyResult<String> {
+        let mut value = serde_json::to_value(self).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        let object = value.as_object_mut().expect("User always serializes to a JSON object");
+        for field in &fields {
+            if field == "id" {
+                return Err(pyo3::exceptions::PyValueError::new_err("field 'id' cannot be excluded"));
+            }
+            if !FIELD_NAMES.contains(&field.as_str()) {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!("unknown field '{}'", field)));
+            }
+            object.remove(field);
+        }
+        serde_json::to_string(&value).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
     }
 
-    /// Create a Python dictionary containing the user's public fields.
+    /// Serialize only the fields that differ from `baseline`, plus `id`, for delta sync.
+    ///
+    /// Optional fields are compared too, so clearing a birthdate yields `"birthdate":null`.
+    /// Keys are written in sorted order. Identical users produce just `{"id":n}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// old = User(1, "Alice", "alice@example.com", 30, True)
+    /// new = User(1, "Alice", "alice@example.com", 31, True)
+    /// new.json_delta(old)  # '{"age":31,"id":1}'
+    /// ```
+    fn json_delta(&self, baseline: &User) -> PyResult<String> {
+        let baseline = baseline.json_fields()?;
+        let mut delta = self.json_fields()?;
+        delta.retain(|key, value| key == "id" || baseline.get(key) != Some(value));
+        serde_json::to_string(&delta).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Serialize the User to compact JSON in the legacy shape, with `"active":1` or `"active":0`
+    /// in place of `status`.
+    ///
+    /// Suspended and deleted users both become `"active":0`, so the exact status does not
+    /// survive a round trip through `from_json_legacy_bool`.
+    ///
+    /// # Errors
     ///
-    /// The returned dictionary has the keys "id", "name", "email", "age", and "active" mapped to the corresponding values from the User.
+    /// Returns a `PyValueError` if serialization fails.
     ///
     /// # Examples
     ///
     /// ```ignore
     /// user = User(1, "Alice", "alice@example.com", 30, True)
-    /// d = user.dict()
-    /// d["id"]
-    /// d["name"]
+    /// user.json_legacy_bool()  # '{"id":1,"name":"Alice","email":"alice@example.com","age":30,"active":1}'
     /// ```
-    fn dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
-        let dict = PyDict::new(py);
-        dict.set_item("id", self.id)?;
-        dict.set_item("name", &self.name)?;
-        dict.set_item("email", &self.email)?;
-        dict.set_item("age", self.age)?;
-        dict.set_item("active", self.active)?;
-        Ok(dict)
+    fn json_legacy_bool(&self) -> PyResult<String> {
+        serde_json::to_string(&LegacyBoolUser::from(self))
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
     }
 
-    /// Return a new User with the same `id` and the provided updated fields.
+    /// Serialize the User as a URL query string such as `id=1&name=Alice&...`.
+    ///
+    /// Values are percent-encoded (spaces become `+`), and fields omitted from `json()` are
+    /// omitted here as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if serialization fails, including when `tags` is non-empty,
+    /// since a query string has no representation for a list.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice Smith", "alice@example.com", 30, True)
+    /// user.to_query_string()  # "id=1&name=Alice+Smith&email=alice%40example.com&age=30&status=active"
+    /// ```
+    fn to_query_string(&self) -> PyResult<String> {
+        serde_urlencoded::to_string(self).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Computes the exact byte length of `json()` without building the JSON string.
     ///
-    /// The returned `User` retains `self.id` while replacing `name`, `email`,
-    /// `age`, and `active` with the supplied values.
+    /// The length is summed from the serialized size of each key and value, so it must stay
+    /// in sync with the field order and escaping used by `json()`; the two always agree.
     ///
     /// # Examples
     ///
     /// ```ignore
     /// user = User(1, "Alice", "alice@example.com", 30, True)
-    /// updated = user.model_copy("Alice B", "aliceb@example.com", 31, False)
-    /// updated.id
-    /// updated.name
+    /// user.json_size_estimate() == len(user.json().encode())  # True
     /// ```
-    #[pyo3(signature = (name, email, age, active))]
-    fn model_copy(&self, name: String, email: String, age: i32, active: bool) -> Self {
-        User {
-            id: self.id,
-            name,
-            email,
-            age,
-            active,
+    fn json_size_estimate(&self) -> usize {
+        // `{"id":` `,"name":` `,"email":` `,"age":` `,"status":` `}`
+        let mut size = 6 + 8 + 9 + 7 + 10 + 1;
+        size += json_int_len(self.id);
+        size += json_string_len(&self.name);
+        size += json_string_len(&self.email);
+        size += json_int_len(self.age);
+        size += self.status.as_str().len() + 2;
+        if let Some(birthdate) = &self.birthdate {
+            // `,"birthdate":`
+            size += 13 + json_string_len(birthdate);
+        }
+        if !is_default_weight(&self.weight) {
+            // `,"weight":`
+            size += 10 + json_float_len(self.weight);
+        }
+        if !self.tags.is_empty() {
+            // `,"tags":[` and `]`, plus one comma between each pair of elements
+            size += 9 + 1 + (self.tags.len() - 1);
+            size += self.tags.iter().map(|tag| json_string_len(tag)).sum::<usize>();
         }
+        size
     }
 
-    /// String representation of the user containing the id, name, and email.
+    /// Serialize the user to a pretty-printed JSON string.
     ///
-    /// The returned string is formatted as `User(id={id}, name='{name}', email='{email}')`.
+    /// Produces a human-readable, pretty-formatted JSON representation of the `User`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if serialization fails.
     ///
     /// # Examples
     ///
     /// ```ignore
     /// user = User(1, "Alice", "alice@example.com", 30, True)
-    /// repr(user)
+    /// s = user.json_pretty()
+    /// '\n' in s
     /// ```
-    fn __repr__(&self) -> String {
-        format!("User(id={}, name='{}', email='{}')", self.id, self.name, self.email)
+    fn json_pretty(&self) -> PyResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Serializes the user in the given format: a `Format` or its name (`"json"`,
+    /// `"json_pretty"`, `"yaml"`, `"msgpack"`, or `"toml"`).
+    ///
+    /// Text formats return `str` and binary formats (MessagePack) return `bytes`. MessagePack
+    /// output encodes the user as a map keyed by field name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` for an unknown format name or if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user.serialize("json") == user.json()  # True
+    /// user.serialize(Format.Msgpack)         # b"\x85\xa2id\x01..."
+    /// ```
+    fn serialize<'py>(&self, py: Python<'py>, fmt: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let format = if let Ok(format) = fmt.cast::<Format>() {
+            *format.borrow()
+        } else {
+            let name: &str = fmt.extract()?;
+            Format::parse(name)
+                .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("unknown format '{}'", name)))?
+        };
+        let err = |e: &dyn std::fmt::Display| pyo3::exceptions::PyValueError::new_err(e.to_string());
+        let text = match format {
+            Format::Json => self.json()?,
+            Format::JsonPretty => self.json_pretty()?,
+            Format::Yaml => serde_yaml::to_string(self).map_err(|e| err(&e))?,
+            Format::Toml => toml::to_string(self).map_err(|e| err(&e))?,
+            Format::Msgpack => {
+                let bytes = rmp_serde::to_vec_named(self).map_err(|e| err(&e))?;
+                return Ok(pyo3::types::PyBytes::new(py, &bytes).into_any());
+            }
+        };
+        Ok(pyo3::types::PyString::new(py, &text).into_any())
+    }
+
+    /// Compares the byte size of `json()` with that of `json_pretty()` to show the cost of formatting.
+    ///
+    /// Returns a dict with `"compact_bytes"`, `"pretty_bytes"`, and `"ratio"` (pretty over compact).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// user.json_overhead()["ratio"]  # about 1.27
+    /// ```
+    fn json_overhead<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let compact_bytes = self.json()?.len();
+        let pretty_bytes = self.json_pretty()?.len();
+        let dict = PyDict::new(py);
+        dict.set_item("compact_bytes", compact_bytes)?;
+        dict.set_item("pretty_bytes", pretty_bytes)?;
+        dict.set_item("ratio", pretty_bytes as f64 / compact_bytes as f64)?;
+        Ok(dict)
+    }
+
+    /// Creates an active User for test fixtures and local development, taking each field from
+    /// an environment variable when it is set.
+    ///
+    /// | Field   | Variable             | Fallback             |
+    /// |---------|----------------------|----------------------|
+    /// | `id`    | `DEFAULT_USER_ID`    | `1`                  |
+    /// | `name`  | `DEFAULT_USER_NAME`  | `"Test User"`        |
+    /// | `email` | `DEFAULT_USER_EMAIL` | `"test@example.com"` |
+    /// | `age`   | `DEFAULT_USER_AGE`   | `30`                 |
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `DEFAULT_USER_ID` or `DEFAULT_USER_AGE` is set but is not an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// os.environ["DEFAULT_USER_EMAIL"] = "dev@example.com"
+    /// User.with_defaults().email  # "dev@example.com"
+    /// ```
+    #[staticmethod]
+    fn with_defaults() -> PyResult<Self> {
+        Self::defaults_from(|key| std::env::var(key).ok())
+    }
+
+    /// Creates a User instance from a JSON string.
+    ///
+    /// Parses `json_str` and returns the corresponding `User` value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `json_str` is not valid JSON or does not match the `User` schema.
+    /// The message is prefixed with the path of the failing field, e.g. `age: invalid type: ...`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// json_str = '{"id":1,"name":"Alice","email":"alice@example.com","age":30,"status":"active"}'
+    /// user = User.from_json(json_str)
+    /// user.id
+    /// user.name
+    /// ```
+    #[staticmethod]
+    fn from_json(json_str: String) -> PyResult<Self> {
+        parse_json(&json_str)
+    }
+
+    /// Serialize the user to a compact binary representation using bincode.
+    ///
+    /// The encoding is considerably smaller and faster to produce than JSON, which makes it
+    /// a good fit for internal RPC. Use `from_bincode` to decode it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if encoding fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// data = user.to_bincode()
+    /// len(data) < len(user.json())  # True
+    /// ```
+    fn to_bincode(&self) -> PyResult<Vec<u8>> {
+        let record = (
+            self.id,
+            &self.name,
+            &self.email,
+            self.age,
+            self.status,
+            &self.birthdate,
+            self.weight,
+            &self.tags,
+        );
+        bincode::serde::encode_to_vec(record, bincode::config::standard())
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Creates a User from bytes produced by `to_bincode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `data` is not a valid bincode-encoded user or has trailing bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User.from_bincode(data)
+    /// ```
+    #[staticmethod]
+    fn from_bincode(data: &[u8]) -> PyResult<Self> {
+        let ((id, name, email, age, status, birthdate, weight, tags), read): (BincodeRecord, usize) =
+            bincode::serde::decode_from_slice(data, bincode::config::standard())
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        if read != data.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unexpected {} trailing bytes after bincode user",
+                data.len() - read
+            )));
+        }
+        Ok(User {
+            id,
+            name,
+            email,
+            age,
+            status,
+            birthdate,
+            weight,
+            tags,
+        })
+    }
+
+    /// Packs the user into a simple binary record for log files.
+    ///
+    /// The layout is the `id` and `age` as 4-byte little-endian integers, one status byte
+    /// (`1` active, `0` suspended, `2` deleted), then `name` and `email`, each as a 4-byte
+    /// little-endian byte length followed by UTF-8 bytes. `birthdate`, `weight`, and `tags` are not stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `name` or `email` is longer than `u32::MAX` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Al", "a@b.io", 30, True)
+    /// len(user.to_fixed_record())  # 4 + 4 + 1 + (4 + 2) + (4 + 6) = 25
+    /// ```
+    fn to_fixed_record(&self) -> PyResult<Vec<u8>> {
+        let mut out = Vec::with_capacity(17 + self.name.len() + self.email.len());
+        out.extend_from_slice(&self.id.to_le_bytes());
+        out.extend_from_slice(&self.age.to_le_bytes());
+        out.push(status_record_byte(self.status));
+        write_record_string(&mut out, &self.name, "name")?;
+        write_record_string(&mut out, &self.email, "email")?;
+        Ok(out)
+    }
+
+    /// Creates a User from bytes produced by `to_fixed_record`.
+    ///
+    /// The `birthdate` is `None`, the `weight` is the default, and `tags` is empty, since the record
+    /// does not carry them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `data` is truncated, has trailing bytes, holds an unknown
+    /// status byte, or contains a name or email that is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User.from_fixed_record(record)
+    /// ```
+    #[staticmethod]
+    fn from_fixed_record(data: &[u8]) -> PyResult<Self> {
+        let mut reader = FixedRecordReader { data };
+        let id = reader.read_i32("id")?;
+        let age = reader.read_i32("age")?;
+        let status = match reader.take(1, "status")?[0] {
+            0 => Status::Suspended,
+            1 => Status::Active,
+            2 => Status::Deleted,
+            other => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "unknown status byte {} in fixed record",
+                    other
+                )))
+            }
+        };
+        let name = reader.read_string("name")?;
+        let email = reader.read_string("email")?;
+        if !reader.data.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unexpected {} trailing bytes after fixed record",
+                reader.data.len()
+            )));
+        }
+        Ok(User {
+            id,
+            name,
+            email,
+            age,
+            status,
+            birthdate: None,
+            weight: DEFAULT_WEIGHT,
+            tags: Vec::new(),
+        })
+    }
+
+    /// Returns true if `json_str` would be accepted by `from_json`, without creating a Python object.
+    ///
+    /// No error message is built on failure, which keeps this cheap for pre-checks in hot loops.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// User.is_valid_json('{"id":1,"name":"A","email":"a@x.com","age":3,"status":"active"}')  # True
+    /// User.is_valid_json('{"id":"one"}')  # False
+    /// ```
+    #[staticmethod]
+    fn is_valid_json(json_str: &str) -> bool {
+        serde_json::from_str::<User>(json_str).is_ok()
+    }
+
+    /// Checks that `json_str` would be accepted by `from_json`, without creating a Python object.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same `PyValueError` as `from_json`, including the path of the failing field.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// User.validate_json('{"id":"one"}')  # raises ValueError: id: invalid type: ...
+    /// ```
+    #[staticmethod]
+    fn validate_json(json_str: &str) -> PyResult<()> {
+        parse_json::<User>(json_str).map(|_| ())
+    }
+
+    /// Creates a User from JSON that may contain trailing commas, e.g. `{"id": 1, ...,}`.
+    ///
+    /// Strict parsing is tried first. If it fails, every comma that directly precedes a closing
+    /// `}` or `]` (ignoring whitespace, and outside string literals) is removed and parsing is
+    /// retried. No other leniency is applied: comments, single quotes, and unquoted keys are
+    /// still rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` from the retry if neither attempt produces a valid `User`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// User.from_json_lenient('{"id":1,"name":"Alice","email":"a@x.com","age":30,"active":true,}')
+    /// ```
+    #[staticmethod]
+    fn from_json_lenient(json_str: String) -> PyResult<Self> {
+        parse_json(&json_str).or_else(|_| parse_json(&strip_trailing_commas(&json_str)))
+    }
+
+    /// Creates a User from legacy JSON whose `active` field is `1`/`0` or `true`/`false`.
+    ///
+    /// The status is derived from `active` as in the constructor.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `json_str` does not match the legacy shape, including an
+    /// `active` value other than a boolean, `0`, or `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User.from_json_legacy_bool(legacy_json)
+    /// User.from_json_legacy_bool(user.json_legacy_bool()).active == user.active  # True
+    /// ```
+    #[staticmethod]
+    fn from_json_legacy_bool(json_str: String) -> PyResult<Self> {
+        parse_json::<LegacyBoolUser>(&json_str).map(User::from)
+    }
+
+    /// Creates a User from a URL query string produced by `to_query_string`.
+    ///
+    /// Values are percent-decoded and converted to the field types. As with `from_json`,
+    /// a boolean `active` parameter is accepted in place of `status`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if a required parameter is missing or a value has the wrong type,
+    /// prefixed with the parameter name, e.g. `age: invalid digit found in string`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// User.from_query_string("id=1&name=Alice&email=alice%40example.com&age=30&active=true").email
+    /// ```
+    #[staticmethod]
+    fn from_query_string(qs: &str) -> PyResult<Self> {
+        let de = serde_urlencoded::Deserializer::new(form_urlencoded::parse(qs.as_bytes()));
+        serde_path_to_error::deserialize(de).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Creates a list of User instances from a JSON array string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `json_str` is not a valid JSON array of `User` objects.
+    /// The message is prefixed with the element index and field path, e.g. `[1].age: invalid type: ...`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// users = User.many_from_json('[{"id":1,"name":"Alice","email":"alice@example.com","age":30,"status":"active"}]')
+    /// users[0].name
+    /// ```
+    #[staticmethod]
+    fn many_from_json(json_str: String) -> PyResult<Vec<Self>> {
+        parse_json(&json_str)
+    }
+
+    /// Create a Python dictionary containing the user's public fields.
+    ///
+    /// The returned dictionary has the keys "id", "name", "email", "age", "status", "birthdate", "weight", and "tags" mapped to the corresponding values
+    /// from the User, with `status` as its lowercase string form to match `json()`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// d = user.dict()
+    /// d["id"]
+    /// d["name"]
+    /// ```
+    fn dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("id", self.id)?;
+        dict.set_item("name", &self.name)?;
+        dict.set_item("email", &self.email)?;
+        dict.set_item("age", self.age)?;
+        dict.set_item("status", self.status.as_str())?;
+        dict.set_item("birthdate", &self.birthdate)?;
+        dict.set_item("weight", self.weight)?;
+        dict.set_item("tags", &self.tags)?;
+        Ok(dict)
+    }
+
+    /// Returns the user as a single-row table: the keys of `dict()`, each mapped to a one-element list.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// user.to_columnar()["name"]  # ["Alice"]
+    /// pd.DataFrame(user.to_columnar())
+    /// ```
+    fn to_columnar<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        columnar_dict(py, &[self])
+    }
+
+    /// Returns a dict of only the requested fields, like a sparse GraphQL response.
+    ///
+    /// Values match those of `dict()`. `"id"` is always included, whether or not it is
+    /// requested, and the keys follow the order of `dict()` rather than of `fields`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `fields` contains a name that is not a User field.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// user.select(["name", "email"])  # {"id": 1, "name": "Alice", "email": "alice@example.com"}
+    /// ```
+    fn select<'py>(&self, py: Python<'py>, fields: Vec<String>) -> PyResult<Bound<'py, PyDict>> {
+        if let Some(unknown) = fields.iter().find(|field| !FIELD_NAMES.contains(&field.as_str())) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!("unknown field '{}'", unknown)));
+        }
+        let all = self.dict(py)?;
+        let selected = PyDict::new(py);
+        for (key, value) in all.iter() {
+            let key_str: String = key.extract()?;
+            if key_str == "id" || fields.contains(&key_str) {
+                selected.set_item(key, value)?;
+            }
+        }
+        Ok(selected)
+    }
+
+    /// Return a new User with the same `id` and the provided updated fields.
+    ///
+    /// The returned `User` retains `self.id`, `self.birthdate`, `self.weight`, and `self.tags` while replacing
+    /// `name`, `email`, `age`, and `active` with the supplied values. `active` maps onto
+    /// the status the same way as assigning `user.active`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// updated = user.model_copy("Alice B", "aliceb@example.com", 31, False)
+    /// updated.id
+    /// updated.name
+    /// ```
+    #[pyo3(signature = (name, email, age, active))]
+    fn model_copy(&self, name: String, email: String, age: i32, active: bool) -> Self {
+        User {
+            id: self.id,
+            name,
+            email,
+            age,
+            status: self.status_for_active(active),
+            birthdate: self.birthdate.clone(),
+            weight: self.weight,
+            tags: self.tags.clone(),
+        }
+    }
+
+    /// Returns the number of days elapsed since the user's birthdate, measured against today's local date.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `birthdate` is `None`, cannot be parsed as an ISO `YYYY-MM-DD` date,
+    /// or lies in the future.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True, birthdate="1994-05-17")
+    /// user.age_in_days()
+    /// ```
+    fn age_in_days(&self) -> PyResult<i64> {
+        days_since_birthdate(self.birthdate.as_deref(), Local::now().date_naive())
+    }
+
+    /// Returns a hex-encoded SHA-256 digest of the user's canonical JSON.
+    ///
+    /// The canonical form sorts object keys, so equal users always hash identically and
+    /// changing any field changes the hash. Useful for caching and change detection.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if serialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// user.content_hash() == User.from_json(user.json()).content_hash()  # True
+    /// ```
+    fn content_hash(&self) -> PyResult<String> {
+        Ok(hex_encode(&self.content_digest()?))
+    }
+
+    /// Returns a stable avatar color such as `"#A1B2C3"`, derived from a SHA-256 hash of the
+    /// user's id and name.
+    ///
+    /// The same id and name always give the same color, across processes and platforms.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// user.avatar_color() == user.avatar_color()  # True
+    /// ```
+    fn avatar_color(&self) -> String {
+        let digest = Sha256::digest(format!("{}:{}", self.id, self.name).as_bytes());
+        format!("#{:02X}{:02X}{:02X}", digest[0], digest[1], digest[2])
+    }
+
+    /// Returns the sum of the two users' ages, widened to `i64` so it cannot overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// a = User(1, "Alice", "alice@example.com", 30, True)
+    /// b = User(2, "Bob", "bob@example.com", 25, True)
+    /// a.combined_age(b)  # 55
+    /// ```
+    fn combined_age(&self, other: &User) -> i64 {
+        i64::from(self.age) + i64::from(other.age)
+    }
+
+    /// Returns the absolute difference between the two users' ages.
+    ///
+    /// The difference is computed without intermediate overflow, so any two non-negative
+    /// ages, up to `i32::MAX`, give an exact result.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyOverflowError` if the difference does not fit in an `i32`, which needs
+    /// one of the ages to be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// a.age_gap(b)  # 5
+    /// b.age_gap(a)  # 5
+    /// ```
+    fn age_gap(&self, other: &User) -> PyResult<i32> {
+        i32::try_from(self.age.abs_diff(other.age))
+            .map_err(|_| pyo3::exceptions::PyOverflowError::new_err("age gap overflow"))
+    }
+
+    /// Returns the Jaccard index of the two users' tag sets: the number of shared tags divided
+    /// by the number of distinct tags across both.
+    ///
+    /// Duplicate tags count once. Two users with no tags at all are considered identical (1.0).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// a = User(1, "Alice", "alice@example.com", 30, True, tags=["admin", "beta"])
+    /// b = User(2, "Bob", "bob@example.com", 25, True, tags=["beta", "ops"])
+    /// a.tag_similarity(b)  # 1/3
+    /// ```
+    fn tag_similarity(&self, other: &User) -> f64 {
+        let ours: HashSet<&str> = self.tags.iter().map(String::as_str).collect();
+        let theirs: HashSet<&str> = other.tags.iter().map(String::as_str).collect();
+        let union = ours.union(&theirs).count();
+        if union == 0 {
+            return 1.0;
+        }
+        ours.intersection(&theirs).count() as f64 / union as f64
+    }
+
+    /// Estimates the total memory owned by this user: the inline struct size plus the heap
+    /// buffers allocated for its string fields.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// user.user_heap_estimate() > user_size_bytes()  # True
+    /// ```
+    fn user_heap_estimate(&self) -> usize {
+        std::mem::size_of::<User>()
+            + self.name.capacity()
+            + self.email.capacity()
+            + self.birthdate.as_ref().map_or(0, String::capacity)
+            + self.tags.capacity() * std::mem::size_of::<String>()
+            + self.tags.iter().map(String::capacity).sum::<usize>()
+    }
+
+    /// Returns whether the user is active, so `if user:` is true only for active users.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// bool(User(1, "Alice", "alice@example.com", 30, False))  # False
+    /// ```
+    fn __bool__(&self) -> bool {
+        self.is_active()
+    }
+
+    /// Sets `age` from an int, an integral float such as `30.0`, or a numeric string such as
+    /// `"30"` or `" 30.0 "`.
+    ///
+    /// The converted age must lie in `0..=MAX_AGE`, as checked by `validate()`; the age is left
+    /// unchanged on any error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` for a float or string that is not an integral number or for an
+    /// age outside the valid range, and a `PyTypeError` for any other type, including `bool`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user.set_age_coerced("30")
+    /// user.set_age_coerced(31.0)
+    /// user.set_age_coerced(30.5)  # raises ValueError
+    /// ```
+    fn set_age_coerced(&mut self, value: Bound<'_, PyAny>) -> PyResult<()> {
+        // `None` marks an integral value too large to convert, which is out of range regardless.
+        let age: Option<i64> = if value.is_instance_of::<PyBool>() {
+            return Err(pyo3::exceptions::PyTypeError::new_err("age must be a number, not bool"));
+        } else if let Ok(int) = value.cast::<PyInt>() {
+            int.extract::<i64>().ok()
+        } else if let Ok(float) = value.cast::<PyFloat>() {
+            integral_age(float.value())?
+        } else if let Ok(string) = value.cast::<PyString>() {
+            let text = string.to_str()?.trim();
+            match text.parse::<i64>() {
+                Ok(age) => Some(age),
+                Err(_) => integral_age(text.parse::<f64>().map_err(|_| {
+                    pyo3::exceptions::PyValueError::new_err(format!("age '{}' is not a number", text))
+                })?)?,
+            }
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "cannot coerce object of type '{}' into an age",
+                value.get_type().name()?
+            )));
+        };
+        self.age = age
+            .and_then(|age| i32::try_from(age).ok())
+            .filter(|age| (0..=MAX_AGE).contains(age))
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(format!("age {} is out of range 0..={}", value, MAX_AGE)))?;
+        Ok(())
+    }
+
+    /// Checks that the user's fields hold sensible values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` describing the first problem found: a blank name, an email
+    /// rejected by `validate_email`, a negative age, or a negative or non-finite weight.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// User(1, "Alice", "alice@example.com", -1, True).validate()  # raises ValueError
+    /// ```
+    fn validate(&self) -> PyResult<()> {
+        match self.validation_error() {
+            Some(reason) => Err(pyo3::exceptions::PyValueError::new_err(reason)),
+            None => Ok(()),
+        }
+    }
+
+    /// Applies an RFC 6902 JSON Patch to the user's top-level fields.
+    ///
+    /// Only the "replace" and "add" operations are supported. Because every User field always
+    /// exists, both simply set the targeted field. The patch is applied to a copy and validated
+    /// as in `validate()`, so the user is left untouched if any operation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `patch` is not a JSON array of operations, uses an unsupported
+    /// op, targets `/id` or a path other than a top-level field, lacks a `value`, or yields a user
+    /// of the wrong shape or one that fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// user.apply_json_patch('[{"op":"replace","path":"/email","value":"alice@new.com"}]')
+    /// user.email  # "alice@new.com"
+    /// ```
+    fn apply_json_patch(&mut self, patch: String) -> PyResult<()> {
+        let operations: Vec<PatchOperation> = parse_json(&patch)?;
+        let mut fields = self.json_fields()?;
+
+        for (index, operation) in operations.into_iter().enumerate() {
+            if operation.op != "replace" && operation.op != "add" {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "[{}]: unsupported patch op '{}'",
+                    index, operation.op
+                )));
+            }
+            let field = patch_field(index, &operation.path)?;
+            let value = operation.value.ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!("[{}]: '{}' requires a value", index, operation.op))
+            })?;
+            fields.insert(field, value);
+        }
+
+        let patched: User = serde_path_to_error::deserialize(serde_json::Value::Object(fields))
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        if let Some(reason) = patched.validation_error() {
+            return Err(pyo3::exceptions::PyValueError::new_err(reason));
+        }
+        *self = patched;
+        Ok(())
+    }
+
+    /// String representation of the user containing the id, name, and email.
+    ///
+    /// The returned string is formatted as `User(id={id}, name='{name}', email='{email}')`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// user = User(1, "Alice", "alice@example.com", 30, True)
+    /// repr(user)
+    /// ```
+    fn __repr__(&self) -> String {
+        format!("User(id={}, name='{}', email='{}')", self.id, self.name, self.email)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, message, user, with_py};
+    use std::ffi::CStr;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn days_since_birthdate_counts_whole_days_to_today() {
+        let today = date("2024-03-01");
+        assert_eq!(days_since_birthdate(Some("2024-02-28"), today).unwrap(), 2);
+        assert_eq!(days_since_birthdate(Some("2023-03-01"), today).unwrap(), 366);
+        assert_eq!(days_since_birthdate(Some("2024-03-01"), today).unwrap(), 0);
+    }
+
+    #[test]
+    fn days_since_birthdate_rejects_missing_invalid_and_future_dates() {
+        with_py(|_| {
+            let today = date("2024-03-01");
+            assert_eq!(message(days_since_birthdate(None, today).unwrap_err()), "birthdate is not set");
+            assert!(message(days_since_birthdate(Some("01/02/1990"), today).unwrap_err()).starts_with("invalid birthdate"));
+            assert!(message(days_since_birthdate(Some("2024-03-02"), today).unwrap_err()).ends_with("is in the future"));
+        });
+    }
+
+    #[test]
+    fn age_in_days_uses_the_birthdate() {
+        let mut alice = user(1, "Alice", "alice@example.com", 30, true);
+        alice.birthdate = Some("1994-05-17".to_string());
+        let expected = (Local::now().date_naive() - date("1994-05-17")).num_days();
+        assert_eq!(alice.age_in_days().unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_errors_name_the_failing_field() {
+        let err = User::from_json(r#"{"id":1,"name":"Alice","email":"a@x.com","age":"thirty","active":true}"#.to_string());
+        let msg = message(err.err().unwrap());
+        assert!(msg.starts_with("age: invalid type"), "{}", msg);
+
+        let err = User::many_from_json(r#"[{"id":1,"name":"A","email":"a@x.com","age":1,"active":true},{"id":"2"}]"#.to_string());
+        let msg = message(err.err().unwrap());
+        assert!(msg.starts_with("[1].id"), "{}", msg);
+    }
+
+    #[test]
+    fn content_hash_is_stable_and_detects_changes() {
+        let alice = user(1, "Alice", "alice@example.com", 30, true);
+        let same = User::from_json(alice.json().unwrap()).unwrap();
+        let hash = alice.content_hash().unwrap();
+        assert_eq!(hash, same.content_hash().unwrap());
+        assert_eq!(hash.len(), 64);
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+
+        let mut older = alice.clone();
+        older.age = 31;
+        assert_ne!(hash, older.content_hash().unwrap());
+    }
+
+    #[test]
+    fn heap_estimate_grows_with_longer_names() {
+        let short = user(1, "Al", "a@x.com", 30, true);
+        let long = user(1, &"Alexander".repeat(10), "a@x.com", 30, true);
+        assert!(short.user_heap_estimate() > std::mem::size_of::<User>());
+        assert!(long.user_heap_estimate() > short.user_heap_estimate());
+    }
+
+    #[test]
+    fn bincode_round_trips_and_is_smaller_than_json() {
+        let mut alice = user(1, "Alice", "alice@example.com", 30, true);
+        alice.birthdate = Some("1994-05-17".to_string());
+        alice.tags = vec!["admin".to_string()];
+        let data = alice.to_bincode().unwrap();
+        assert!(data.len() < alice.json().unwrap().len(), "{} bytes", data.len());
+
+        let decoded = User::from_bincode(&data).unwrap();
+        assert_eq!(decoded.json().unwrap(), alice.json().unwrap());
+    }
+
+    #[test]
+    fn from_bincode_rejects_truncated_and_trailing_bytes() {
+        let data = user(1, "Alice", "alice@example.com", 30, true).to_bincode().unwrap();
+        assert!(User::from_bincode(&data[..data.len() - 1]).is_err());
+        let mut padded = data.clone();
+        padded.push(0);
+        assert_eq!(
+            message(User::from_bincode(&padded).err().unwrap()),
+            "unexpected 1 trailing bytes after bincode user"
+        );
+    }
+
+    #[test]
+    fn domain_is_lowercased_and_requires_an_at_sign() {
+        assert_eq!(user(1, "Alice", "alice@Example.com", 30, true).domain().unwrap(), "example.com");
+        assert_eq!(
+            message(user(1, "Alice", "alice", 30, true).domain().unwrap_err()),
+            "email 'alice' does not contain '@'"
+        );
+    }
+
+    #[test]
+    fn inactive_user_is_falsy() {
+        assert!(!user(1, "Alice", "alice@example.com", 30, false).__bool__());
+        assert!(user(1, "Alice", "alice@example.com", 30, true).__bool__());
+    }
+
+    #[test]
+    fn json_string_len_counts_escapes() {
+        assert_eq!(json_string_len(""), 2);
+        assert_eq!(json_string_len("a\"b\\c\n"), 2 + 6 + 3);
+        assert_eq!(json_string_len("\u{1}"), 2 + 6);
+        assert_eq!(json_string_len("é"), 2 + 2);
+    }
+
+    #[test]
+    fn json_size_estimate_matches_json_length() {
+        let mut tagged = user(-42, "Zoë \"Z\" Tab\t", "z@example.com", 0, false);
+        tagged.birthdate = Some("2001-02-03".to_string());
+        tagged.weight = 0.1;
+        tagged.tags = vec!["a".to_string(), "b\\c".to_string(), String::new()];
+        let mut heavy = user(i32::MAX, "", "", i32::MIN, true);
+        heavy.weight = 1e300;
+        for u in [user(1, "Alice", "alice@example.com", 30, true), tagged, heavy] {
+            assert_eq!(u.json_size_estimate(), u.json().unwrap().len(), "{}", u.json().unwrap());
+        }
+    }
+
+    #[test]
+    fn from_json_reads_the_status() {
+        let suspended =
+            User::from_json(r#"{"id":1,"name":"A","email":"a@x.com","age":3,"status":"suspended"}"#.to_string())
+                .unwrap();
+        assert_eq!(suspended.status, Status::Suspended);
+        assert!(!suspended.active());
+
+        let legacy = User::from_json(r#"{"id":1,"name":"A","email":"a@x.com","age":3,"active":true}"#.to_string())
+            .unwrap();
+        assert_eq!(legacy.status, Status::Active);
+        assert!(User::from_json(r#"{"id":1,"name":"A","email":"a@x.com","age":3,"status":"gone"}"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn json_patch_replaces_the_email() {
+        let mut alice = user(1, "Alice", "alice@example.com", 30, true);
+        alice
+            .apply_json_patch(r#"[{"op":"replace","path":"/email","value":"alice@new.com"},{"op":"add","path":"/age","value":31}]"#.to_string())
+            .unwrap();
+        assert_eq!((alice.email.as_str(), alice.age), ("alice@new.com", 31));
+    }
+
+    #[test]
+    fn json_patch_clears_the_birthdate_with_null() {
+        let mut alice = user(1, "Alice", "alice@example.com", 30, true);
+        alice.birthdate = Some("1994-05-17".to_string());
+        alice.apply_json_patch(r#"[{"op":"replace","path":"/birthdate","value":null}]"#.to_string()).unwrap();
+        assert_eq!(alice.birthdate, None);
+    }
+
+    #[test]
+    fn json_patch_rejects_bad_ops_paths_and_ids() {
+        let mut alice = user(1, "Alice", "alice@example.com", 30, true);
+        let mut patch = |ops: &str| message(alice.apply_json_patch(ops.to_string()).unwrap_err());
+        assert_eq!(patch(r#"[{"op":"replace","path":"/id","value":2}]"#), "[0]: invalid patch path '/id'");
+        assert_eq!(patch(r#"[{"op":"replace","path":"/nickname","value":"Al"}]"#), "[0]: invalid patch path '/nickname'");
+        assert_eq!(patch(r#"[{"op":"remove","path":"/email"}]"#), "[0]: unsupported patch op 'remove'");
+        assert_eq!(patch(r#"[{"op":"replace","path":"/email"}]"#), "[0]: 'replace' requires a value");
+        assert!(patch(r#"[{"op":"replace","path":"/email","value":"not-an-email"}]"#).starts_with("invalid email"));
+        assert_eq!((alice.id, alice.email.as_str()), (1, "alice@example.com"));
+    }
+
+    #[test]
+    fn pretty_json_costs_more_than_compact() {
+        with_py(|py| {
+            let alice = user(1, "Alice", "alice@example.com", 30, true);
+            let overhead = alice.json_overhead(py).unwrap();
+            let get = |key: &str| overhead.get_item(key).unwrap().unwrap();
+            let compact: usize = get("compact_bytes").extract().unwrap();
+            let pretty: usize = get("pretty_bytes").extract().unwrap();
+            assert_eq!(compact, alice.json().unwrap().len());
+            assert!(pretty >= compact);
+            assert!(get("ratio").extract::<f64>().unwrap() > 1.0);
+        });
+    }
+
+    #[test]
+    fn json_excluding_omits_named_fields() {
+        let alice = user(1, "Alice", "alice@example.com", 30, true);
+        assert_eq!(
+            alice.json_excluding(vec!["email".to_string(), "age".to_string()]).unwrap(),
+            r#"{"id":1,"name":"Alice","status":"active"}"#
+        );
+        assert_eq!(message(alice.json_excluding(vec!["id".to_string()]).unwrap_err()), "field 'id' cannot be excluded");
+        assert_eq!(message(alice.json_excluding(vec!["nickname".to_string()]).unwrap_err()), "unknown field 'nickname'");
+    }
+
+    #[test]
+    fn with_defaults_reads_the_default_user_variables() {
+        let fallback = User::defaults_from(|_| None).unwrap();
+        assert_eq!((fallback.id, fallback.email.as_str(), fallback.age), (1, "test@example.com", 30));
+
+        let configured = User::defaults_from(|key| match key {
+            "DEFAULT_USER_EMAIL" => Some("dev@example.com".to_string()),
+            "DEFAULT_USER_AGE" => Some("41".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!((configured.email.as_str(), configured.age), ("dev@example.com", 41));
+        assert_eq!(configured.name, "Test User");
+
+        let invalid = User::defaults_from(|key| (key == "DEFAULT_USER_AGE").then(|| "old".to_string()));
+        assert_eq!(message(invalid.err().unwrap()), "invalid value 'old' for DEFAULT_USER_AGE");
+    }
+
+    #[test]
+    fn fixed_record_round_trips() {
+        for status in [Status::Active, Status::Suspended, Status::Deleted] {
+            let mut original = user(-7, "José", "jose@example.com", 41, true);
+            original.status = status;
+            let decoded = User::from_fixed_record(&original.to_fixed_record().unwrap()).unwrap();
+            assert_eq!(decoded.json().unwrap(), original.json().unwrap());
+        }
+    }
+
+    #[test]
+    fn truncated_fixed_record_raises() {
+        let data = user(1, "Alice", "alice@example.com", 30, true).to_fixed_record().unwrap();
+        for len in 0..data.len() {
+            assert!(User::from_fixed_record(&data[..len]).is_err(), "accepted {} of {} bytes", len, data.len());
+        }
+        assert_eq!(
+            message(User::from_fixed_record(&data[..3]).err().unwrap()),
+            "truncated fixed record: expected 4 bytes for id, found 3"
+        );
+    }
+
+    #[test]
+    fn legacy_bool_json_round_trips() {
+        let alice = user(1, "Alice", "alice@example.com", 30, true);
+        let legacy = alice.json_legacy_bool().unwrap();
+        assert_eq!(legacy, r#"{"id":1,"name":"Alice","email":"alice@example.com","age":30,"active":1}"#);
+        assert_eq!(User::from_json_legacy_bool(legacy).unwrap().json().unwrap(), alice.json().unwrap());
+
+        let bob = user(2, "Bob", "bob@example.com", 25, false);
+        assert!(bob.json_legacy_bool().unwrap().ends_with(r#""active":0}"#));
+        assert!(!User::from_json_legacy_bool(bob.json_legacy_bool().unwrap()).unwrap().is_active());
+    }
+
+    #[test]
+    fn legacy_bool_parser_accepts_both_forms() {
+        let parse = |active: &str| {
+            User::from_json_legacy_bool(format!(r#"{{"id":1,"name":"A","email":"a@x.com","age":3,"active":{}}}"#, active))
+        };
+        assert!(parse("true").unwrap().is_active());
+        assert!(!parse("0").unwrap().is_active());
+        assert!(parse("2").is_err());
+        assert!(parse("\"yes\"").is_err());
+    }
+
+    #[test]
+    fn select_returns_requested_fields_plus_id() {
+        with_py(|py| {
+            let alice = user(1, "Alice", "alice@example.com", 30, true);
+            let selected = alice.select(py, vec!["email".to_string(), "name".to_string()]).unwrap();
+            assert_py_eq(&selected, c"{'id': 1, 'name': 'Alice', 'email': 'alice@example.com'}");
+            assert_eq!(
+                message(alice.select(py, vec!["password".to_string()]).unwrap_err()),
+                "unknown field 'password'"
+            );
+        });
+    }
+
+    #[test]
+    fn query_string_round_trips() {
+        let mut alice = user(1, "Alice Smith", "alice+tag@example.com", 30, false);
+        alice.birthdate = Some("1994-05-17".to_string());
+        alice.weight = 2.5;
+        let qs = alice.to_query_string().unwrap();
+        assert!(qs.starts_with("id=1&name=Alice+Smith&email=alice%2Btag%40example.com&age=30"), "{}", qs);
+        assert_eq!(User::from_query_string(&qs).unwrap().json().unwrap(), alice.json().unwrap());
+    }
+
+    #[test]
+    fn query_string_reports_missing_and_bad_params() {
+        let legacy = User::from_query_string("id=1&name=A&email=a%40x.com&age=3&active=true").unwrap();
+        assert!(legacy.is_active());
+        assert!(message(User::from_query_string("id=1&name=A&email=a%40x.com&age=old&active=true").err().unwrap())
+            .starts_with("age: "));
+        assert!(User::from_query_string("id=1&name=A&age=3&active=true").is_err());
+    }
+
+    #[test]
+    fn serialize_dispatches_on_format() {
+        with_py(|py| {
+            let alice = user(1, "Alice", "alice@example.com", 30, true);
+            let name = |fmt: &str| pyo3::types::PyString::new(py, fmt).into_any();
+
+            let json: String = alice.serialize(py, &name("json")).unwrap().extract().unwrap();
+            assert_eq!(json, alice.json().unwrap());
+
+            let packed = alice.serialize(py, &name("msgpack")).unwrap();
+            let bytes = packed.cast::<pyo3::types::PyBytes>().unwrap().as_bytes();
+            let decoded: User = rmp_serde::from_slice(bytes).unwrap();
+            assert_eq!(decoded.json().unwrap(), alice.json().unwrap());
+
+            let pretty = Bound::new(py, Format::JsonPretty).unwrap().into_any();
+            assert_eq!(alice.serialize(py, &pretty).unwrap().extract::<String>().unwrap(), alice.json_pretty().unwrap());
+            assert_eq!(message(alice.serialize(py, &name("xml")).unwrap_err()), "unknown format 'xml'");
+        });
+    }
+
+    #[test]
+    fn is_valid_json_and_validate_json_agree() {
+        let valid = r#"{"id":1,"name":"A","email":"a@x.com","age":3,"status":"active"}"#;
+        assert!(User::is_valid_json(valid));
+        assert!(User::validate_json(valid).is_ok());
+
+        for invalid in ["", "[]", r#"{"id":1,"name":"A","email":"a@x.com","status":"active"}"#, "{\"id\":1,"] {
+            assert!(!User::is_valid_json(invalid), "{}", invalid);
+            assert!(User::validate_json(invalid).is_err(), "{}", invalid);
+        }
+        let wrong_type = r#"{"id":1,"name":"A","email":"a@x.com","age":"3","status":"active"}"#;
+        assert!(message(User::validate_json(wrong_type).unwrap_err()).starts_with("age: invalid type"));
+    }
+
+    #[test]
+    fn avatar_color_is_deterministic_hex() {
+        let alice = user(1, "Alice", "alice@example.com", 30, true);
+        let color = alice.avatar_color();
+        assert_eq!(color, user(1, "Alice", "other@example.com", 99, false).avatar_color());
+        assert_eq!(color.len(), 7);
+        assert!(color.starts_with('#'));
+        assert!(color[1..].chars().all(|c| c.is_ascii_digit() || ('A'..='F').contains(&c)), "{}", color);
+        assert_ne!(color, user(2, "Alice", "alice@example.com", 30, true).avatar_color());
+    }
+
+    #[test]
+    fn to_columnar_wraps_each_field_in_a_list() {
+        with_py(|py| {
+            let columns = user(1, "Alice", "alice@example.com", 30, true).to_columnar(py).unwrap();
+            assert_py_eq(
+                &columns,
+                c"{'id': [1], 'name': ['Alice'], 'email': ['alice@example.com'], 'age': [30], 'status': ['active'], \
+                   'birthdate': [None], 'weight': [1.0], 'tags': [[]]}",
+            );
+        });
+    }
+
+    #[test]
+    fn strip_trailing_commas_leaves_strings_alone() {
+        assert_eq!(strip_trailing_commas(r#"{"a":[1,2,],}"#), r#"{"a":[1,2]}"#);
+        assert_eq!(strip_trailing_commas("[1 ,\n ]"), "[1 \n ]");
+        assert_eq!(strip_trailing_commas(r#"{"s":"x,}","t":"\",]"}"#), r#"{"s":"x,}","t":"\",]"}"#);
+        assert_eq!(strip_trailing_commas("[1,2]"), "[1,2]");
+    }
+
+    #[test]
+    fn lenient_parsing_accepts_trailing_commas() {
+        let json = r#"{"id":1,"name":"Alice","email":"a@x.com","age":30,"tags":["a",],"active":true,}"#;
+        assert!(User::from_json(json.to_string()).is_err());
+        let alice = User::from_json_lenient(json.to_string()).unwrap();
+        assert_eq!((alice.name.as_str(), alice.tags.as_slice()), ("Alice", ["a".to_string()].as_slice()));
+        assert!(User::from_json_lenient(r#"{"id":1,,}"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn tag_similarity_is_the_jaccard_index() {
+        let tagged = |tags: &[&str]| {
+            let mut u = user(1, "U", "u@x.com", 30, true);
+            u.tags = tags.iter().map(|t| t.to_string()).collect();
+            u
+        };
+        assert_eq!(tagged(&["a", "b"]).tag_similarity(&tagged(&["c"])), 0.0);
+        assert_eq!(tagged(&["a", "b"]).tag_similarity(&tagged(&["b", "a", "a"])), 1.0);
+        assert_eq!(tagged(&["a", "b", "c"]).tag_similarity(&tagged(&["b", "c", "d"])), 0.5);
+        assert_eq!(tagged(&[]).tag_similarity(&tagged(&[])), 1.0);
+        assert_eq!(tagged(&[]).tag_similarity(&tagged(&["a"])), 0.0);
+    }
+
+    #[test]
+    fn age_arithmetic_near_i32_max() {
+        let oldest = user(1, "Old", "old@example.com", i32::MAX, true);
+        let almost = user(2, "Almost", "almost@example.com", i32::MAX - 1, true);
+        let newborn = user(3, "New", "new@example.com", 0, true);
+        assert_eq!(oldest.validation_error(), None);
+        assert_eq!(oldest.combined_age(&oldest), 2 * i64::from(i32::MAX));
+        assert_eq!(oldest.combined_age(&almost), 4_294_967_293);
+        assert_eq!(oldest.age_gap(&newborn).unwrap(), i32::MAX);
+        assert_eq!(newborn.age_gap(&oldest).unwrap(), i32::MAX);
+        assert_eq!(almost.age_gap(&oldest).unwrap(), 1);
+
+        let negative = user(4, "Neg", "neg@example.com", -1, true);
+        assert_eq!(negative.validation_error().unwrap(), "age -1 is out of range 0..=2147483647");
+        assert!(negative.age_gap(&oldest).is_err());
+    }
+
+    #[test]
+    fn json_delta_keeps_id_and_changed_fields() {
+        let old = user(1, "Alice", "alice@example.com", 30, true);
+        let mut new = old.clone();
+        assert_eq!(new.json_delta(&old).unwrap(), r#"{"id":1}"#);
+        new.age = 31;
+        assert_eq!(new.json_delta(&old).unwrap(), r#"{"age":31,"id":1}"#);
+    }
+
+    #[test]
+    fn set_age_coerced_accepts_integral_numbers_and_strings() {
+        with_py(|py| {
+            let mut alice = user(1, "Alice", "alice@example.com", 30, true);
+            let accepted: [(&CStr, i32); 6] = [
+                (c"31", 31),
+                (c"32.0", 32),
+                (c"'33'", 33),
+                (c"' 34.0 '", 34),
+                (c"0", 0),
+                (c"'2147483647'", i32::MAX),
+            ];
+            for (value, age) in accepted {
+                alice.set_age_coerced(py.eval(value, None, None).unwrap()).unwrap();
+                assert_eq!(alice.age, age, "{:?}", value);
+            }
+        });
+    }
+
+    #[test]
+    fn set_age_coerced_rejects_other_input_and_keeps_the_age() {
+        with_py(|py| {
+            let mut alice = user(1, "Alice", "alice@example.com", 30, true);
+            let rejected: [(&CStr, &str); 8] = [
+                (c"30.5", "age must be an integral value, got 30.5"),
+                (c"float('nan')", "age must be an integral value, got NaN"),
+                (c"'thirty'", "age 'thirty' is not a number"),
+                (c"'30.5'", "age must be an integral value, got 30.5"),
+                (c"-1", "age -1 is out of range 0..=2147483647"),
+                (c"2147483648", "age 2147483648 is out of range 0..=2147483647"),
+                (c"True", "age must be a number, not bool"),
+                (c"[30]", "cannot coerce object of type 'list' into an age"),
+            ];
+            for (value, expected) in rejected {
+                let err = alice.set_age_coerced(py.eval(value, None, None).unwrap()).unwrap_err();
+                assert_eq!(message(err), expected, "{:?}", value);
+                assert_eq!(alice.age, 30);
+            }
+        });
+    }
+
+    #[test]
+    fn json_canonical_sorts_keys_alphabetically() {
+        let mut alice = user(1, "Alice", "alice@example.com", 30, true);
+        alice.birthdate = Some("1994-05-06".to_string());
+        alice.tags = vec!["beta".to_string()];
+        let json = alice.json_canonical().unwrap();
+        assert!(!json.contains(' '), "{}", json);
+        let keys: Vec<String> = serde_json::from_str::<BTreeMap<String, serde_json::Value>>(&json)
+            .unwrap()
+            .into_keys()
+            .collect();
+        let positions: Vec<usize> = keys.iter().map(|key| json.find(&format!("\"{}\":", key)).unwrap()).collect();
+        assert!(positions.is_sorted(), "{}", json);
+        assert_eq!(
+            alice.json_canonical().unwrap(),
+            concat!(
+                r#"{"age":30,"birthdate":"1994-05-06","email":"alice@example.com","id":1,"#,
+                r#""name":"Alice","status":"active","tags":["beta"]}"#
+            )
+        );
     }
 }
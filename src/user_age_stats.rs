@@ -0,0 +1,68 @@
+use pyo3::prelude::*;
+
+use crate::stats::Stats;
+use crate::user::User;
+
+/// Computes summary statistics of the users' ages.
+///
+/// Unlike `age_summary`, which returns a dict, the result is a `Stats` object with
+/// attribute access and a readable repr, and it also includes the sum and standard deviation.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A `Stats` with the count, sum, mean, min, max, and sample standard deviation of the ages
+///
+/// # Examples
+///
+/// ```ignore
+/// stats = user_age_stats(users)
+/// stats.mean
+/// stats  # Stats(count=2, sum=55.0, mean=27.5, min=25.0, max=30.0, stddev=3.5355339059327378)
+/// ```
+#[pyfunction]
+pub fn user_age_stats(users: Vec<PyRef<User>>) -> Stats {
+    let ages: Vec<f64> = users.iter().map(|user| f64::from(user.age)).collect();
+    Stats::from_values(&ages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+
+    fn repr(py: Python<'_>, stats: Stats) -> String {
+        Bound::new(py, stats).unwrap().repr().unwrap().to_string()
+    }
+
+    #[test]
+    fn repr_lists_every_field() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![user(1, "Alice", "alice@example.com", 30, true), user(2, "Bob", "bob@example.com", 25, false)],
+            );
+            assert_eq!(
+                repr(py, user_age_stats(borrow_all(&users))),
+                "Stats(count=2, sum=55.0, mean=27.5, min=25.0, max=30.0, stddev=3.5355339059327378)"
+            );
+        });
+    }
+
+    #[test]
+    fn single_sample_has_nan_stddev() {
+        with_py(|py| {
+            let users = bind(py, vec![user(1, "Alice", "alice@example.com", 30, true)]);
+            assert_eq!(
+                repr(py, user_age_stats(borrow_all(&users))),
+                "Stats(count=1, sum=30.0, mean=30.0, min=30.0, max=30.0, stddev=nan)"
+            );
+            let empty = user_age_stats(Vec::new());
+            assert_eq!((empty.count, empty.sum), (0, 0.0));
+            assert!(empty.mean.is_nan() && empty.min.is_nan() && empty.max.is_nan());
+        });
+    }
+}
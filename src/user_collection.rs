@@ -0,0 +1,199 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyIterator, PyList};
+
+use crate::user::User;
+
+/// An ordered collection of users shared with Python by reference
+#[pyclass]
+pub struct UserCollection {
+    users: Vec<Py<User>>,
+}
+
+#[pymethods]
+impl UserCollection {
+    /// Creates a new UserCollection holding the given users (empty by default).
+    ///
+    /// The collection stores references, so mutating a user obtained from it is visible
+    /// through the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// users = UserCollection([User(1, "Alice", "alice@example.com", 30, True)])
+    /// len(users)
+    /// ```
+    #[new]
+    #[pyo3(signature = (users=Vec::new()))]
+    fn new(users: Vec<Py<User>>) -> Self {
+        UserCollection { users }
+    }
+
+    /// Appends a user to the end of the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// users.append(User(2, "Bob", "bob@example.com", 25, True))
+    /// ```
+    fn append(&mut self, user: Py<User>) {
+        self.users.push(user);
+    }
+
+    /// Returns the number of users in the collection.
+    fn __len__(&self) -> usize {
+        self.users.len()
+    }
+
+    /// Returns the user at `index`; negative indices count from the end, as with lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyIndexError` if `index` is out of range.
+    fn __getitem__(&self, py: Python<'_>, index: isize) -> PyResult<Py<User>> {
+        let resolved = if index < 0 {
+            self.users.len().checked_sub(index.unsigned_abs())
+        } else {
+            Some(index as usize)
+        };
+        resolved
+            .and_then(|index| self.users.get(index))
+            .map(|user| user.clone_ref(py))
+            .ok_or_else(|| pyo3::exceptions::PyIndexError::new_err("UserCollection index out of range"))
+    }
+
+    /// Iterates over the users in insertion order.
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, &self.users)?.try_iter()
+    }
+
+    /// Returns true if the collection holds a user with the given id.
+    ///
+    /// `item` may be an id or a User, in which case its id is looked up. Anything else is
+    /// never contained.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// 1 in users      # True
+    /// alice in users  # True
+    /// ```
+    fn __contains__(&self, py: Python<'_>, item: &Bound<'_, PyAny>) -> bool {
+        let id = if let Ok(user) = item.cast::<User>() {
+            user.borrow().id
+        } else if let Ok(id) = item.extract::<i32>() {
+            id
+        } else {
+            return false;
+        };
+        self.index_of(py, id).is_some()
+    }
+
+    /// Returns the position of the first user with the given id, or `None` if there is none.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// users.index_of(2)  # 1
+    /// ```
+    fn index_of(&self, py: Python<'_>, id: i32) -> Option<usize> {
+        self.users.iter().position(|user| user.borrow(py).id == id)
+    }
+
+    /// Validates every user and summarizes the results.
+    ///
+    /// # Returns
+    ///
+    /// A dictionary with `valid`, the number of users that pass `User.validate`, and
+    /// `invalid`, a list of `(index, reason)` tuples for those that do not
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// users.validate_all()  # {"valid": 2, "invalid": [(1, "age -1 is out of range 0..=2147483647")]}
+    /// ```
+    fn validate_all<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let mut valid: usize = 0;
+        let mut invalid: Vec<(usize, String)> = Vec::new();
+        for (index, user) in self.users.iter().enumerate() {
+            match user.borrow(py).validation_error() {
+                Some(reason) => invalid.push((index, reason)),
+                None => valid += 1,
+            }
+        }
+
+        let dict = PyDict::new(py);
+        dict.set_item("valid", valid)?;
+        dict.set_item("invalid", invalid)?;
+        Ok(dict)
+    }
+
+    /// Formats a string representation of the collection including its size.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// repr(UserCollection())  # "UserCollection(len=0)"
+    /// ```
+    fn __repr__(&self) -> String {
+        format!("UserCollection(len={})", self.users.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, user, with_py};
+
+    #[test]
+    fn validate_all_reports_the_out_of_range_age() {
+        with_py(|py| {
+            let users = vec![
+                user(1, "Alice", "alice@example.com", 30, true),
+                user(2, "Bob", "bob@example.com", -4, true),
+                user(3, "Carol", "carol@example.com", 41, false),
+            ];
+            let collection = UserCollection::new(users.into_iter().map(|u| Py::new(py, u).unwrap()).collect());
+            let report = collection.validate_all(py).unwrap();
+            assert_py_eq(
+                &report,
+                c"{'valid': 2, 'invalid': [(1, 'age -4 is out of range 0..=2147483647')]}",
+            );
+        });
+    }
+
+    fn three_users(py: Python<'_>) -> UserCollection {
+        let users = [(1, "Alice"), (2, "Bob"), (3, "Carol")]
+            .into_iter()
+            .map(|(id, name)| Py::new(py, user(id, name, "u@x.com", 30, true)).unwrap())
+            .collect();
+        UserCollection::new(users)
+    }
+
+    #[test]
+    fn supports_negative_indexing() {
+        with_py(|py| {
+            let collection = three_users(py);
+            assert_eq!(collection.__getitem__(py, -1).unwrap().borrow(py).name, "Carol");
+            assert_eq!(collection.__getitem__(py, -3).unwrap().borrow(py).name, "Alice");
+            assert_eq!(collection.__getitem__(py, 1).unwrap().borrow(py).name, "Bob");
+            for index in [3, -4, isize::MIN] {
+                let err = collection.__getitem__(py, index).err().unwrap();
+                assert!(err.is_instance_of::<pyo3::exceptions::PyIndexError>(py));
+            }
+        });
+    }
+
+    #[test]
+    fn membership_and_index_of_match_by_id() {
+        with_py(|py| {
+            let collection = three_users(py);
+            let bob_again = Bound::new(py, user(2, "Robert", "r@x.com", 50, false)).unwrap().into_any();
+            assert!(collection.__contains__(py, &bob_again));
+            assert!(collection.__contains__(py, &3i32.into_pyobject(py).unwrap().into_any()));
+            assert!(!collection.__contains__(py, &4i32.into_pyobject(py).unwrap().into_any()));
+            assert!(!collection.__contains__(py, &"Alice".into_pyobject(py).unwrap().into_any()));
+            assert_eq!(collection.index_of(py, 3), Some(2));
+            assert_eq!(collection.index_of(py, 9), None);
+        });
+    }
+}
@@ -0,0 +1,129 @@
+use lru::LruCache;
+use pyo3::prelude::*;
+use std::num::NonZeroUsize;
+
+use crate::user::{parse_json, User};
+
+/// A `User.from_json` front end that keeps recently parsed users in an LRU cache
+#[pyclass]
+pub struct UserParser {
+    /// Parsed users keyed by their JSON text, in order of last use.
+    cache: LruCache<String, User>,
+    hits: u64,
+    misses: u64,
+}
+
+#[pymethods]
+impl UserParser {
+    /// Creates a new UserParser that caches up to `capacity` distinct JSON strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `capacity` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// parser = UserParser(capacity=256)
+    /// ```
+    #[new]
+    #[pyo3(signature = (capacity=128))]
+    fn new(capacity: usize) -> PyResult<Self> {
+        let capacity = NonZeroUsize::new(capacity)
+            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("capacity must be greater than 0"))?;
+        Ok(UserParser {
+            cache: LruCache::new(capacity),
+            hits: 0,
+            misses: 0,
+        })
+    }
+
+    /// Parses `json` like `User.from_json`, returning a copy of the cached user if the same
+    /// string was parsed recently.
+    ///
+    /// When the cache is full, the least recently used entry is evicted in constant time.
+    /// Failed parses are not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PyValueError` if `json` is not a valid `User` JSON object.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// parser.parse(data)  # miss: parsed
+    /// parser.parse(data)  # hit: copied from the cache
+    /// ```
+    fn parse(&mut self, json: String) -> PyResult<User> {
+        if let Some(user) = self.cache.get(&json) {
+            self.hits += 1;
+            return Ok(user.clone());
+        }
+
+        self.misses += 1;
+        let user: User = parse_json(&json)?;
+        self.cache.put(json, user.clone());
+        Ok(user)
+    }
+
+    /// Returns how many `parse` calls were served from the cache.
+    fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns how many `parse` calls had to parse the JSON.
+    fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Formats a string representation of the parser including its cache usage.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// repr(UserParser())  # "UserParser(size=0, capacity=128)"
+    /// ```
+    fn __repr__(&self) -> String {
+        format!("UserParser(size={}, capacity={})", self.cache.len(), self.cache.cap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::message;
+
+    fn json(id: i32) -> String {
+        format!(r#"{{"id":{},"name":"U","email":"u@x.com","age":30,"status":"active"}}"#, id)
+    }
+
+    #[test]
+    fn repeated_parse_is_a_cache_hit() {
+        let mut parser = UserParser::new(4).unwrap();
+        assert_eq!(parser.parse(json(1)).unwrap().id, 1);
+        assert_eq!(parser.parse(json(1)).unwrap().id, 1);
+        assert_eq!((parser.hits(), parser.misses()), (1, 1));
+        assert!(parser.parse("{}".to_string()).is_err());
+        assert_eq!(parser.__repr__(), "UserParser(size=1, capacity=4)");
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_entry() {
+        let mut parser = UserParser::new(2).unwrap();
+        parser.parse(json(1)).unwrap();
+        parser.parse(json(2)).unwrap();
+        parser.parse(json(1)).unwrap();
+        parser.parse(json(3)).unwrap();
+        assert_eq!((parser.hits(), parser.misses()), (1, 3));
+
+        parser.parse(json(1)).unwrap();
+        assert_eq!(parser.hits(), 2);
+        parser.parse(json(2)).unwrap();
+        assert_eq!(parser.misses(), 4);
+    }
+
+    #[test]
+    fn capacity_must_be_positive() {
+        assert_eq!(message(UserParser::new(0).err().unwrap()), "capacity must be greater than 0");
+    }
+}
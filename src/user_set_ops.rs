@@ -0,0 +1,105 @@
+use pyo3::prelude::*;
+use std::collections::HashSet;
+
+use crate::user::User;
+
+/// Keeps the first user for each id, in order, skipping ids already in `seen`.
+fn unique_by_id(users: Vec<User>, seen: &mut HashSet<i32>) -> Vec<User> {
+    users.into_iter().filter(|user| seen.insert(user.id)).collect()
+}
+
+/// Returns the users of `left` whose id also appears in `right`.
+///
+/// Users are matched by id only. The result keeps the order of `left` and holds each id
+/// once; when the lists disagree on other fields, the first `left` version is kept.
+///
+/// # Examples
+///
+/// ```ignore
+/// [u.id for u in users_intersection(left, right)]  # ids in both, in left order
+/// ```
+#[pyfunction]
+pub fn users_intersection(left: Vec<User>, right: Vec<User>) -> Vec<User> {
+    let right_ids: HashSet<i32> = right.iter().map(|user| user.id).collect();
+    let left: Vec<User> = left.into_iter().filter(|user| right_ids.contains(&user.id)).collect();
+    unique_by_id(left, &mut HashSet::new())
+}
+
+/// Returns every user of `left` followed by the users of `right` whose id is not in `left`.
+///
+/// Users are matched by id only and each id appears once. When the lists disagree on other
+/// fields, the first `left` version is kept.
+///
+/// # Examples
+///
+/// ```ignore
+/// [u.id for u in users_union(left, right)]  # left ids, then new right ids
+/// ```
+#[pyfunction]
+pub fn users_union(left: Vec<User>, right: Vec<User>) -> Vec<User> {
+    let mut seen = HashSet::new();
+    let mut union = unique_by_id(left, &mut seen);
+    union.extend(unique_by_id(right, &mut seen));
+    union
+}
+
+/// Returns the users of `left` whose id does not appear in `right`.
+///
+/// Users are matched by id only. The result keeps the order of `left` and holds each id once.
+///
+/// # Examples
+///
+/// ```ignore
+/// [u.id for u in users_difference(left, right)]  # ids only in left
+/// ```
+#[pyfunction]
+pub fn users_difference(left: Vec<User>, right: Vec<User>) -> Vec<User> {
+    let mut seen: HashSet<i32> = right.iter().map(|user| user.id).collect();
+    unique_by_id(left, &mut seen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::user;
+
+    fn left() -> Vec<User> {
+        vec![
+            user(1, "Alice", "a@x.com", 30, true),
+            user(2, "Bob", "b@x.com", 25, true),
+            user(2, "Bob (dup)", "b@x.com", 25, true),
+            user(3, "Carol", "c@x.com", 41, true),
+        ]
+    }
+
+    fn right() -> Vec<User> {
+        vec![
+            user(3, "Caroline", "c@x.com", 41, false),
+            user(4, "Dan", "d@x.com", 52, true),
+            user(2, "Robert", "b@x.com", 25, true),
+        ]
+    }
+
+    fn summary(users: Vec<User>) -> Vec<(i32, String)> {
+        users.into_iter().map(|u| (u.id, u.name)).collect()
+    }
+
+    #[test]
+    fn intersection_keeps_left_versions() {
+        let expected = [(2, "Bob".to_string()), (3, "Carol".to_string())];
+        assert_eq!(summary(users_intersection(left(), right())), expected);
+    }
+
+    #[test]
+    fn union_is_deduplicated_and_prefers_left() {
+        let ids: Vec<i32> = users_union(left(), right()).iter().map(|u| u.id).collect();
+        assert_eq!(ids, [1, 2, 3, 4]);
+        assert_eq!(users_union(left(), right())[2].name, "Carol");
+    }
+
+    #[test]
+    fn difference_removes_right_ids() {
+        assert_eq!(summary(users_difference(left(), right())), [(1, "Alice".to_string())]);
+        assert_eq!(users_difference(right(), left()).iter().map(|u| u.id).collect::<Vec<_>>(), [4]);
+    }
+}
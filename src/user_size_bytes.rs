@@ -0,0 +1,32 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Returns the inline size of the Rust `User` struct in bytes.
+///
+/// Every `User` field lives at a fixed offset within this many bytes, which is what makes
+/// direct field access from Rust cheap compared to a `getattr` dictionary lookup.
+/// String contents are stored on the heap and are not included; see `User.user_heap_estimate`.
+///
+/// # Examples
+///
+/// ```ignore
+/// user_size_bytes()  # e.g. 88 on 64-bit platforms
+/// ```
+#[pyfunction]
+pub fn user_size_bytes() -> usize {
+    std::mem::size_of::<User>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stack_size_covers_the_inline_fields() {
+        let size = user_size_bytes();
+        assert!(size > 0);
+        // Two i32s, the status, and the `String`/`Vec` headers are all stored inline.
+        assert!(size >= 2 * std::mem::size_of::<i32>() + 3 * std::mem::size_of::<String>());
+    }
+}
@@ -0,0 +1,97 @@
+use pyo3::prelude::*;
+
+use crate::status::Status;
+use crate::user::{User, DEFAULT_WEIGHT};
+
+/// Builds users from five parallel column lists, zipping them row by row.
+///
+/// Converting whole columns in one call avoids constructing each `User` through a
+/// separate FFI crossing.
+///
+/// # Arguments
+///
+/// * `ids`, `names`, `emails`, `ages`, `actives` - Equal-length lists holding one field each
+///
+/// # Returns
+///
+/// A list of users, one per row
+///
+/// # Errors
+///
+/// Returns a `PyValueError` naming the first column whose length differs from `ids`.
+///
+/// # Examples
+///
+/// ```ignore
+/// users = users_from_columns([1, 2], ["Alice", "Bob"], ["a@x.com", "b@x.com"], [30, 25], [True, False])
+/// ```
+#[pyfunction]
+pub fn users_from_columns(
+    ids: Vec<i32>,
+    names: Vec<String>,
+    emails: Vec<String>,
+    ages: Vec<i32>,
+    actives: Vec<bool>,
+) -> PyResult<Vec<User>> {
+    let expected = ids.len();
+    for (column, len) in [
+        ("names", names.len()),
+        ("emails", emails.len()),
+        ("ages", ages.len()),
+        ("actives", actives.len()),
+    ] {
+        if len != expected {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "column '{}' has length {}, expected {} to match 'ids'",
+                column, len, expected
+            )));
+        }
+    }
+
+    let users = ids
+        .into_iter()
+        .zip(names)
+        .zip(emails)
+        .zip(ages)
+        .zip(actives)
+        .map(|((((id, name), email), age), active)| User {
+            id,
+            name,
+            email,
+            age,
+            status: Status::from_active(active),
+            birthdate: None,
+            weight: DEFAULT_WEIGHT,
+            tags: Vec::new(),
+        })
+        .collect();
+    Ok(users)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::message;
+
+    fn names() -> Vec<String> {
+        vec!["Alice".to_string(), "Bob".to_string()]
+    }
+
+    fn emails() -> Vec<String> {
+        vec!["a@x.com".to_string(), "b@x.com".to_string()]
+    }
+
+    #[test]
+    fn zips_matching_columns_into_users() {
+        let users = users_from_columns(vec![1, 2], names(), emails(), vec![30, 25], vec![true, false]).unwrap();
+        assert_eq!(users.len(), 2);
+        assert_eq!((users[1].id, users[1].name.as_str(), users[1].age), (2, "Bob", 25));
+        assert_eq!(users[1].status, Status::from_active(false));
+    }
+
+    #[test]
+    fn names_the_mismatched_column() {
+        let err = users_from_columns(vec![1, 2], names(), emails(), vec![30], vec![true, false]).err().unwrap();
+        assert_eq!(message(err), "column 'ages' has length 1, expected 2 to match 'ids'");
+    }
+}
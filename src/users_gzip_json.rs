@@ -0,0 +1,81 @@
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use pyo3::prelude::*;
+use std::io::Read;
+
+use crate::user::{parse_json, User};
+
+/// Serializes users to a JSON array and gzip-compresses it, for compact archival.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// The gzip-compressed bytes of the JSON array
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if serialization or compression fails.
+///
+/// # Examples
+///
+/// ```ignore
+/// blob = users_to_gzip_json(users)
+/// ```
+#[pyfunction]
+pub fn users_to_gzip_json(users: Vec<PyRef<User>>) -> PyResult<Vec<u8>> {
+    let records: Vec<&User> = users.iter().map(|user| &**user).collect();
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    serde_json::to_writer(&mut encoder, &records).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    encoder.finish().map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+}
+
+/// Decompresses a blob produced by `users_to_gzip_json` and parses the users back out.
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `data` is not valid gzip, or if the decompressed JSON is not
+/// an array of users (with the element index and field path, as in `User.many_from_json`).
+///
+/// # Examples
+///
+/// ```ignore
+/// users = users_from_gzip_json(users_to_gzip_json(users))
+/// ```
+#[pyfunction]
+pub fn users_from_gzip_json(data: &[u8]) -> PyResult<Vec<User>> {
+    let mut json = String::new();
+    GzDecoder::new(data)
+        .read_to_string(&mut json)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+    parse_json(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+
+    #[test]
+    fn round_trips_and_compresses_repetitive_data() {
+        with_py(|py| {
+            let users = bind(py, (0..200).map(|id| user(id, "Alice", "alice@example.com", 30, id % 2 == 0)).collect());
+            let data = users_to_gzip_json(borrow_all(&users)).unwrap();
+
+            let raw = serde_json::to_string(&users.iter().map(|u| u.borrow().clone()).collect::<Vec<User>>()).unwrap();
+            assert!(data.len() < raw.len(), "{} >= {}", data.len(), raw.len());
+
+            let decoded = users_from_gzip_json(&data).unwrap();
+            assert_eq!(decoded.len(), 200);
+            assert_eq!(serde_json::to_string(&decoded).unwrap(), raw);
+        });
+    }
+
+    #[test]
+    fn rejects_data_that_is_not_gzip() {
+        assert!(users_from_gzip_json(b"[]").is_err());
+    }
+}
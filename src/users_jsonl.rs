@@ -0,0 +1,91 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Serializes users as JSON Lines: one compact JSON object per line.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// A string with one line per user, each terminated by `\n`
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if serialization fails.
+///
+/// # Examples
+///
+/// ```ignore
+/// data = users_to_jsonl([User(1, "Alice", "alice@example.com", 30, True)])
+/// ```
+#[pyfunction]
+pub fn users_to_jsonl(users: Vec<PyRef<User>>) -> PyResult<String> {
+    let mut out = String::new();
+    for user in &users {
+        let line = serde_json::to_string(&**user).map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Parses JSON Lines produced by `users_to_jsonl` back into users.
+///
+/// Blank (or whitespace-only) lines are skipped.
+///
+/// # Errors
+///
+/// Returns a `PyValueError` naming the 1-based line number and field path of the first
+/// line that fails to parse, e.g. `line 3: age: invalid type: ...`.
+///
+/// # Examples
+///
+/// ```ignore
+/// users = users_from_jsonl(users_to_jsonl(users))
+/// ```
+#[pyfunction]
+pub fn users_from_jsonl(data: &str) -> PyResult<Vec<User>> {
+    let mut users = Vec::new();
+    for (idx, line) in data.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_err = |e: &dyn std::fmt::Display| pyo3::exceptions::PyValueError::new_err(format!("line {}: {}", idx + 1, e));
+        let mut de = serde_json::Deserializer::from_str(line);
+        let user: User = serde_path_to_error::deserialize(&mut de).map_err(|e| line_err(&e))?;
+        de.end().map_err(|e| line_err(&e))?;
+        users.push(user);
+    }
+    Ok(users)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, message, user, with_py};
+
+    #[test]
+    fn round_trips_and_skips_blank_lines() {
+        with_py(|py| {
+            let users = bind(py, vec![user(1, "Alice", "alice@example.com", 30, true), user(2, "Bob", "bob@test.org", 25, false)]);
+            let jsonl = users_to_jsonl(borrow_all(&users)).unwrap();
+            assert_eq!(jsonl.lines().count(), 2);
+
+            let (first, second) = jsonl.split_once('\n').unwrap();
+            let with_blank = format!("{}\n\n   \n{}", first, second);
+            let parsed = users_from_jsonl(&with_blank).unwrap();
+            let summary: Vec<(i32, &str, bool)> = parsed.iter().map(|u| (u.id, u.name.as_str(), u.is_active())).collect();
+            assert_eq!(summary, [(1, "Alice", true), (2, "Bob", false)]);
+        });
+    }
+
+    #[test]
+    fn reports_the_failing_line_number() {
+        let data = "{\"id\":1,\"name\":\"A\",\"email\":\"a@x.com\",\"age\":1,\"active\":true}\n\n{\"id\":2}";
+        let msg = message(users_from_jsonl(data).err().unwrap());
+        assert!(msg.starts_with("line 3: "), "{}", msg);
+    }
+}
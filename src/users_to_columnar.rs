@@ -0,0 +1,61 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::user::User;
+
+/// Builds a dict of parallel column lists, one entry per user, with the same keys and
+/// value forms as `User.dict()`.
+pub(crate) fn columnar_dict<'py>(py: Python<'py>, users: &[&User]) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("id", users.iter().map(|u| u.id).collect::<Vec<_>>())?;
+    dict.set_item("name", users.iter().map(|u| u.name.as_str()).collect::<Vec<_>>())?;
+    dict.set_item("email", users.iter().map(|u| u.email.as_str()).collect::<Vec<_>>())?;
+    dict.set_item("age", users.iter().map(|u| u.age).collect::<Vec<_>>())?;
+    dict.set_item("status", users.iter().map(|u| u.status.as_str()).collect::<Vec<_>>())?;
+    dict.set_item("birthdate", users.iter().map(|u| u.birthdate.as_deref()).collect::<Vec<_>>())?;
+    dict.set_item("weight", users.iter().map(|u| u.weight).collect::<Vec<_>>())?;
+    dict.set_item("tags", users.iter().map(|u| &u.tags).collect::<Vec<_>>())?;
+    Ok(dict)
+}
+
+/// Converts users into a dict of equal-length column lists, ready for `pd.DataFrame(...)`.
+///
+/// The columns are the keys of `User.dict()`, and row `i` of every column comes from user `i`.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `users` - A list of PyO3 User objects
+///
+/// # Examples
+///
+/// ```ignore
+/// df = pd.DataFrame(users_to_columnar(users))
+/// ```
+#[pyfunction]
+pub fn users_to_columnar<'py>(py: Python<'py>, users: Vec<PyRef<'py, User>>) -> PyResult<Bound<'py, PyDict>> {
+    let users: Vec<&User> = users.iter().map(|user| &**user).collect();
+    columnar_dict(py, &users)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{assert_py_eq, bind, borrow_all, user, with_py};
+
+    #[test]
+    fn every_column_has_one_entry_per_user() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![user(1, "Alice", "a@x.com", 30, true), user(2, "Bob", "b@x.com", 25, false)],
+            );
+            let columns = users_to_columnar(py, borrow_all(&users)).unwrap();
+            assert_eq!(columns.len(), 8);
+            for (name, column) in columns.iter() {
+                assert_eq!(column.len().unwrap(), 2, "column {}", name);
+            }
+            assert_py_eq(&columns.get_item("status").unwrap().unwrap(), c"['active', 'suspended']");
+        });
+    }
+}
@@ -0,0 +1,119 @@
+use pyo3::prelude::*;
+use unicode_width::UnicodeWidthStr;
+
+use crate::user::User;
+
+/// Column headers, in display order.
+const HEADERS: [&str; 5] = ["id", "name", "email", "age", "status"];
+
+/// Appends `cell` to `line`, padded with spaces to `width` display columns.
+fn push_padded(line: &mut String, cell: &str, width: usize) {
+    line.push_str(cell);
+    line.extend(std::iter::repeat_n(' ', width.saturating_sub(cell.width())));
+}
+
+/// Joins one row of cells with ` | `, padding every column but the last to its width.
+fn format_row(cells: &[String], widths: &[usize]) -> String {
+    let mut line = String::new();
+    for (i, (cell, &width)) in cells.iter().zip(widths).enumerate() {
+        if i > 0 {
+            line.push_str(" | ");
+        }
+        if i + 1 == cells.len() {
+            line.push_str(cell);
+        } else {
+            push_padded(&mut line, cell, width);
+        }
+    }
+    line
+}
+
+/// Renders users as an aligned plain-text table for CLI output.
+///
+/// The table has a header row, a `-+-` separator, and one row per user. Columns are padded
+/// to the widest value by display width, so names containing multibyte or wide (e.g. CJK)
+/// characters still line up in a terminal.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// The table as a string, one line per row, terminated by `\n`
+///
+/// # Examples
+///
+/// ```ignore
+/// print(users_to_table(users))
+/// # id | name  | email             | age | status
+/// # ---+-------+-------------------+-----+-------
+/// # 1  | Alice | alice@example.com | 30  | active
+/// ```
+#[pyfunction]
+pub fn users_to_table(users: Vec<PyRef<User>>) -> String {
+    let rows: Vec<Vec<String>> = users
+        .iter()
+        .map(|u| {
+            vec![
+                u.id.to_string(),
+                u.name.clone(),
+                u.email.clone(),
+                u.age.to_string(),
+                u.status.as_str().to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = HEADERS.iter().map(|h| h.width()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+
+    let header: Vec<String> = HEADERS.iter().map(|h| h.to_string()).collect();
+    let separator = widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>().join("-+-");
+
+    let mut table = format_row(&header, &widths);
+    table.push('\n');
+    table.push_str(&separator);
+    table.push('\n');
+    for row in &rows {
+        table.push_str(&format_row(row, &widths));
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+
+    /// Returns the display column of each `|` in `line`.
+    fn bar_columns(line: &str) -> Vec<usize> {
+        line.match_indices('|').map(|(i, _)| line[..i].width()).collect()
+    }
+
+    #[test]
+    fn renders_header_and_aligned_rows() {
+        with_py(|py| {
+            let users = bind(
+                py,
+                vec![
+                    user(1, "Al", "al@example.com", 30, true),
+                    user(12345, "Zoë 山田", "z@x.io", 101, false),
+                ],
+            );
+            let table = users_to_table(borrow_all(&users));
+            let lines: Vec<&str> = table.lines().collect();
+            assert_eq!(lines.len(), 4);
+            assert_eq!(lines[0], "id    | name     | email          | age | status");
+            assert!(lines[1].chars().all(|c| c == '-' || c == '+'));
+            for line in &lines[2..] {
+                assert_eq!(bar_columns(line), bar_columns(lines[0]), "{}", line);
+            }
+        });
+    }
+}
@@ -0,0 +1,118 @@
+use once_cell::sync::Lazy;
+use pyo3::prelude::*;
+use regex::Regex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether `validate_email` applies the strict pattern; toggled by `set_strict_email`.
+static STRICT_EMAIL: AtomicBool = AtomicBool::new(false);
+
+/// An RFC 5322 subset: a dot-atom local part and a domain of LDH labels ending in an
+/// alphabetic top-level label. Unanchored, so it can also find addresses inside text.
+pub(crate) const STRICT_EMAIL_REGEX: &str = r"[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[A-Za-z0-9!#$%&'*+/=?^_`{|}~-]+)*@(?:[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?\.)+[A-Za-z]{2,63}";
+
+static STRICT_EMAIL_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(&format!("^{}$", STRICT_EMAIL_REGEX)).expect("strict email pattern is valid"));
+
+/// Longest local part allowed by RFC 5321.
+const MAX_LOCAL_LEN: usize = 64;
+/// Longest address that fits in an RFC 5321 forward path.
+const MAX_EMAIL_LEN: usize = 254;
+
+fn is_lenient_email(email: &str) -> bool {
+    if email.chars().any(char::is_whitespace) {
+        return false;
+    }
+    let mut parts = email.split('@');
+    let (Some(local), Some(domain), None) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    !local.is_empty() && domain.contains('.') && !domain.starts_with('.') && !domain.ends_with('.')
+}
+
+fn is_strict_email(email: &str) -> bool {
+    let local_len = email.find('@').unwrap_or(email.len());
+    email.len() <= MAX_EMAIL_LEN && local_len <= MAX_LOCAL_LEN && STRICT_EMAIL_PATTERN.is_match(email)
+}
+
+/// Checks the structure of an email address.
+///
+/// By default the check is lenient: an address is valid when it contains exactly one `@`,
+/// has a non-empty local part, contains no whitespace, and its domain has at least one
+/// interior `.` (not at the start or end).
+///
+/// After `set_strict_email(True)`, addresses must also match an RFC 5322 subset. Addresses
+/// the lenient check accepts but the strict one rejects include:
+///
+/// - consecutive dots, or a leading or trailing dot, in the local part (`a..b@example.com`, `.a@example.com`)
+/// - consecutive dots in the domain (`a@example..com`)
+/// - characters outside the RFC 5322 atom set, such as `(),:;<>[]\"` and non-ASCII letters
+/// - domain labels starting or ending with `-`, or containing `_`
+/// - a top-level label that is not 2 to 63 letters (`a@example.c`, `a@example.123`)
+/// - a local part over 64 bytes or an address over 254 bytes
+///
+/// The strict check does not accept quoted local parts or IP-literal domains.
+///
+/// # Examples
+///
+/// ```ignore
+/// validate_email("alice@example.com")  # True
+/// validate_email("alice@localhost")    # False
+/// validate_email("a..b@example.com")   # True, but False in strict mode
+/// ```
+#[pyfunction]
+pub fn validate_email(email: &str) -> bool {
+    is_lenient_email(email) && (!STRICT_EMAIL.load(Ordering::Relaxed) || is_strict_email(email))
+}
+
+/// Turns strict email validation on or off for the whole module.
+///
+/// Affects every caller of `validate_email`, including `validate_emails` and `User.validate`.
+///
+/// # Examples
+///
+/// ```ignore
+/// set_strict_email(True)
+/// ```
+#[pyfunction]
+pub fn set_strict_email(enabled: bool) {
+    STRICT_EMAIL.store(enabled, Ordering::Relaxed);
+}
+
+/// Returns true if strict email validation is enabled.
+///
+/// # Examples
+///
+/// ```ignore
+/// strict_email_enabled()  # False by default
+/// ```
+#[pyfunction]
+pub fn strict_email_enabled() -> bool {
+    STRICT_EMAIL.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These exercise the two checks directly; toggling the global flag here would race other tests.
+    #[test]
+    fn strict_rejects_what_lenient_accepts() {
+        let lenient_only = ["a..b@example.com", ".alice@example.com", "alice@exa_mple.com", "alice@example.c0m"];
+        for email in lenient_only {
+            assert!(is_lenient_email(email), "{}", email);
+            assert!(!is_strict_email(email), "{}", email);
+        }
+        let long_local = format!("{}@example.com", "a".repeat(MAX_LOCAL_LEN + 1));
+        assert!(is_lenient_email(&long_local) && !is_strict_email(&long_local));
+    }
+
+    #[test]
+    fn both_accept_ordinary_addresses() {
+        for email in ["alice@example.com", "first.last+tag@mail.example.co.uk", "o'neil@x.io"] {
+            assert!(is_lenient_email(email) && is_strict_email(email), "{}", email);
+        }
+        for email in ["nope", "a@b", "a b@x.com", "@x.com", "a@@x.com"] {
+            assert!(!is_lenient_email(email), "{}", email);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::validate_email::validate_email;
+
+/// Validates a batch of email addresses in a single call.
+///
+/// Each address is checked with `validate_email`, so callers can pre-screen large
+/// lists with one FFI crossing instead of one per address.
+///
+/// # Arguments
+///
+/// * `py` - Python GIL token
+/// * `emails` - The email addresses to check
+///
+/// # Returns
+///
+/// A dictionary mapping each email to `True` if it is valid and `False` otherwise
+///
+/// # Examples
+///
+/// ```ignore
+/// validate_emails(["alice@example.com", "not-an-email"])
+/// # {"alice@example.com": True, "not-an-email": False}
+/// ```
+#[pyfunction]
+pub fn validate_emails(py: Python<'_>, emails: Vec<String>) -> PyResult<Bound<'_, PyDict>> {
+    let dict = PyDict::new(py);
+    for email in &emails {
+        dict.set_item(email, validate_email(email))?;
+    }
+    Ok(dict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::with_py;
+
+    #[test]
+    fn maps_each_address_to_its_validity() {
+        with_py(|py| {
+            let emails = vec!["alice@example.com".to_string(), "not-an-email".to_string(), "bob@test.org".to_string()];
+            let result = validate_emails(py, emails).unwrap();
+            assert_eq!(result.len(), 3);
+            let valid = |email: &str| result.get_item(email).unwrap().unwrap().extract::<bool>().unwrap();
+            assert!(valid("alice@example.com"));
+            assert!(!valid("not-an-email"));
+            assert!(valid("bob@test.org"));
+        });
+    }
+}
@@ -0,0 +1,64 @@
+use pyo3::prelude::*;
+
+use crate::user::User;
+
+/// Computes the mean age of a list of users, weighting each user by its `weight`.
+///
+/// # Arguments
+///
+/// * `users` - A list of PyO3 User objects
+///
+/// # Returns
+///
+/// `sum(weight * age) / sum(weight)`
+///
+/// # Errors
+///
+/// Returns a `PyZeroDivisionError` if the total weight is 0, including for an empty list.
+///
+/// # Examples
+///
+/// ```ignore
+/// users = [User(1, "A", "a@x.com", 20, True, weight=3.0), User(2, "B", "b@x.com", 40, True)]
+/// weighted_average_age(users)  # 25.0
+/// ```
+#[pyfunction]
+pub fn weighted_average_age(users: Vec<PyRef<User>>) -> PyResult<f64> {
+    let (weighted_sum, total_weight) = users.iter().fold((0.0, 0.0), |(sum, total), user| {
+        (sum + user.weight * user.age as f64, total + user.weight)
+    });
+    if total_weight == 0.0 {
+        return Err(pyo3::exceptions::PyZeroDivisionError::new_err("total weight is zero"));
+    }
+    Ok(weighted_sum / total_weight)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, borrow_all, user, with_py};
+
+    fn weighted(age: i32, weight: f64) -> User {
+        let mut u = user(1, "U", "u@x.com", age, true);
+        u.weight = weight;
+        u
+    }
+
+    #[test]
+    fn unequal_weights_shift_the_mean() {
+        with_py(|py| {
+            let users = bind(py, vec![weighted(20, 3.0), weighted(40, 1.0)]);
+            assert_eq!(weighted_average_age(borrow_all(&users)).unwrap(), 25.0);
+        });
+    }
+
+    #[test]
+    fn zero_total_weight_raises() {
+        with_py(|py| {
+            let users = bind(py, vec![weighted(20, 0.0)]);
+            let err = weighted_average_age(borrow_all(&users)).unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyZeroDivisionError>(py));
+            assert!(weighted_average_age(Vec::new()).is_err());
+        });
+    }
+}
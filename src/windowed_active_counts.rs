@@ -0,0 +1,79 @@
+use pyo3::prelude::*;
+use std::collections::VecDeque;
+
+use crate::user::User;
+
+/// Counts the active users in each sliding window over a stream of users.
+///
+/// The input is consumed lazily, holding only the last `window` active flags, so any
+/// iterable (including a generator) works. One count is emitted per full window, so `n`
+/// users yield `n - window + 1` counts, or none if there are fewer than `window` users.
+///
+/// # Arguments
+///
+/// * `users` - Any iterable of PyO3 User objects
+/// * `window` - The number of consecutive users in each window
+///
+/// # Errors
+///
+/// Returns a `PyValueError` if `window` is 0.
+///
+/// # Examples
+///
+/// ```ignore
+/// # active flags: T, F, T, T, F
+/// windowed_active_counts(users, 3)  # [2, 2, 2]
+/// ```
+#[pyfunction]
+pub fn windowed_active_counts(users: Bound<'_, PyAny>, window: usize) -> PyResult<Vec<i64>> {
+    if window == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err("window must be greater than 0"));
+    }
+
+    let mut recent: VecDeque<bool> = VecDeque::new();
+    let mut active_in_window: i64 = 0;
+    let mut counts = Vec::new();
+    for user_obj in users.try_iter()? {
+        let active = user_obj?.extract::<PyRef<User>>()?.is_active();
+        if recent.len() == window && recent.pop_front() == Some(true) {
+            active_in_window -= 1;
+        }
+        recent.push_back(active);
+        active_in_window += active as i64;
+        if recent.len() == window {
+            counts.push(active_in_window);
+        }
+    }
+    Ok(counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{bind, user, with_py};
+    use pyo3::types::PyList;
+
+    fn stream<'py>(py: Python<'py>, flags: &[bool]) -> Bound<'py, PyAny> {
+        let users = flags.iter().enumerate().map(|(i, &active)| user(i as i32, "U", "u@x.com", 30, active));
+        let users = bind(py, users.collect());
+        PyList::new(py, users).unwrap().into_any()
+    }
+
+    #[test]
+    fn counts_active_users_per_window() {
+        with_py(|py| {
+            let flags = [true, false, true, true, false, false, true];
+            assert_eq!(windowed_active_counts(stream(py, &flags), 3).unwrap(), [2, 2, 2, 1, 1]);
+            assert_eq!(windowed_active_counts(stream(py, &flags), 1).unwrap(), [1, 0, 1, 1, 0, 0, 1]);
+            assert!(windowed_active_counts(stream(py, &flags[..2]), 3).unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn huge_window_yields_no_counts() {
+        with_py(|py| {
+            assert!(windowed_active_counts(stream(py, &[true, false]), usize::MAX).unwrap().is_empty());
+            assert!(windowed_active_counts(stream(py, &[true]), 0).is_err());
+        });
+    }
+}